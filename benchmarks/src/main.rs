@@ -16,7 +16,7 @@ use std::time::Duration;
 use std::collections::HashMap;
 
 use stdweb::Reference;
-use stdweb::web::document;
+use stdweb::web::{document, window};
 use stdweb::web::event::ClickEvent;
 use stdweb::unstable::TryInto;
 use stdweb::traits::*;
@@ -55,7 +55,7 @@ struct Timer {
 impl Stopwatch for Timer {
     fn now() -> Self {
         if !*IS_NODEJS {
-            let timestamp: f64 = js!( return performance.now(); ).try_into().unwrap();
+            let timestamp = window().performance().now();
             Timer {
                 timestamp: timestamp / 1000_f64
             }
@@ -196,5 +196,13 @@ fn main() {
 
             move || js!( @(no_return) var test = @{&references[0]}; )
         });
+        bencher.add( "typed-array-into-vec-generic", || {
+            let value = js!( return new Float32Array( 1024 ); );
+            move || { let vec: Vec< f64 > = js!( return Array.from( @{&value} ); ).try_into().unwrap(); vec }
+        });
+        bencher.add( "typed-array-into-vec-direct", || {
+            let value = js!( return new Float32Array( 1024 ); );
+            move || value.clone().into_typed_vec::< f32 >().unwrap()
+        });
     });
 }
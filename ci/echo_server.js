@@ -0,0 +1,30 @@
+// A tiny HTTP server for the tests which need to see what the browser
+// actually sent; every request is answered with a JSON description of itself.
+var http = require( "http" );
+var port = process.env.ECHO_SERVER_PORT || 8765;
+
+http.createServer( function( request, response ) {
+    var chunks = [];
+    request.on( "data", function( chunk ) {
+        chunks.push( chunk );
+    });
+
+    request.on( "end", function() {
+        response.setHeader( "Access-Control-Allow-Origin", "*" );
+        response.setHeader( "Access-Control-Allow-Methods", "GET, HEAD, POST, PUT, DELETE" );
+        response.setHeader( "Access-Control-Allow-Headers", request.headers[ "access-control-request-headers" ] || "*" );
+
+        if( request.method === "OPTIONS" ) {
+            response.end();
+            return;
+        }
+
+        response.setHeader( "Content-Type", "application/json" );
+        response.end( JSON.stringify( {
+            method: request.method,
+            url: request.url,
+            headers: request.headers,
+            body: Buffer.concat( chunks ).toString( "utf8" )
+        }));
+    });
+}).listen( port );
@@ -23,6 +23,11 @@ pushd stdweb-internal-macros > /dev/null
 cargo test
 popd > /dev/null
 
+echo "Starting the echo server..."
+node ci/echo_server.js &
+ECHO_SERVER_PID=$!
+trap "kill $ECHO_SERVER_PID" EXIT
+
 # echo "Testing for wasm32-unknown-unknown (wasm-bindgen)..."
 # wasm-pack test --headless --chrome -- --features web_test
 
@@ -31,12 +31,14 @@ fn main() {
     let canvas: CanvasElement = document().query_selector( "#canvas" ).unwrap().unwrap().try_into().unwrap();
     let context: CanvasRenderingContext2d = canvas.get_context().unwrap();
 
-    canvas.set_width(canvas.offset_width() as u32);
-    canvas.set_height(canvas.offset_height() as u32);
+    let (width, height) = canvas.size();
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
 
     window().add_event_listener( enclose!( (canvas) move |_: ResizeEvent| {
-        canvas.set_width(canvas.offset_width() as u32);
-        canvas.set_height(canvas.offset_height() as u32);
+        let (width, height) = canvas.size();
+        canvas.set_width(width as u32);
+        canvas.set_height(height as u32);
     }));
 
     canvas.add_event_listener( enclose!( (context) move |event: MouseMoveEvent| {
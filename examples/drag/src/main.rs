@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use stdweb::traits::*;
 use stdweb::web::{
     document,
+    HtmlElement,
     IParentNode,
 };
 use stdweb::web::event::{
@@ -62,18 +63,20 @@ fn add_event_listeners<F>(team: &'static str, change_team: F)
 }
 
 fn render(team_a: &Vec<String>, team_b: &Vec<String>) {
-    let inner_html = |vec: &Vec<String>|
-        vec
-            .iter()
-            .map(|x| format!("<div class=\"char\" draggable=\"true\">{}</div>", x))
-            .collect::<Vec<String>>()
-            .join("\n");
-    ;
-
-    let team_a_elem = document().query_selector(".team-a-chars").unwrap().unwrap();
-    let team_b_elem = document().query_selector(".team-b-chars").unwrap().unwrap();
-    js!(@{team_a_elem.as_ref()}.innerHTML = @{inner_html(team_a)});
-    js!(@{team_b_elem.as_ref()}.innerHTML = @{inner_html(team_b)});
+    let render_team = |selector: &str, team: &Vec<String>| {
+        let container = document().query_selector(selector).unwrap().unwrap();
+        container.set_text_content("");
+        for name in team {
+            let element: HtmlElement = document().create_element("div").unwrap().try_into().unwrap();
+            element.class_list().add("char").unwrap();
+            element.set_draggable(true);
+            element.set_text_content(name);
+            container.append_child(&element);
+        }
+    };
+
+    render_team(".team-a-chars", team_a);
+    render_team(".team-b-chars", team_b);
 }
 
 fn drag_and_drop_elements_example() {
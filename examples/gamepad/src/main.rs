@@ -41,7 +41,11 @@ fn get_pad_title(pad: &Gamepad) -> Element {
     ));
 
     div.append_child(&elem_content("h3",
-        &format!("Mapping: {}", match pad.mapping() { GamepadMappingType::Standard => "standard", _ => "non-standard" })
+        &format!("Mapping: {}", match pad.mapping() {
+            GamepadMappingType::Standard => "standard",
+            GamepadMappingType::XrStandard => "xr-standard",
+            GamepadMappingType::NoMapping => "non-standard",
+        })
     ));
 
     div.append_child(&elem_content("h3",
@@ -3,7 +3,6 @@ extern crate stdweb;
 
 #[macro_use]
 extern crate serde_derive;
-extern crate serde_json;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -191,16 +190,13 @@ fn update_dom( state: &StateRef ) {
     }
 
     // Save the state into local storage.
-    let state_json = serde_json::to_string( &*state_borrow ).unwrap();
-    window().local_storage().insert( "state", state_json.as_str() ).unwrap();
+    window().local_storage().insert_serde( "state", &*state_borrow ).unwrap();
 }
 
 fn main() {
     stdweb::initialize();
 
-    let state = window().local_storage().get( "state" ).and_then( |state_json| {
-        serde_json::from_str( state_json.as_str() ).ok()
-    }).unwrap_or_else( State::new );
+    let state = window().local_storage().get_serde( "state" ).unwrap_or_else( State::new );
     let state = Rc::new( RefCell::new( state ) );
 
     let title_entry: InputElement = document().query_selector( ".new-todo" ).unwrap().unwrap().try_into().unwrap();
@@ -140,15 +140,17 @@ fn main() {
     let canvas: CanvasElement = document().query_selector( "#canvas" ).unwrap().unwrap().try_into().unwrap();
     let context: gl = canvas.get_context().unwrap();
 
-    canvas.set_width(canvas.offset_width() as u32);
-    canvas.set_height(canvas.offset_height() as u32);
+    let (width, height) = canvas.size();
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
 
     context.clear_color(1.0, 0.0, 0.0, 1.0);
     context.clear(gl::COLOR_BUFFER_BIT);
 
     window().add_event_listener( enclose!( (canvas) move |_: ResizeEvent| {
-        canvas.set_width(canvas.offset_width() as u32);
-        canvas.set_height(canvas.offset_height() as u32);
+        let (width, height) = canvas.size();
+        canvas.set_width(width as u32);
+        canvas.set_height(height as u32);
     }));
 
     let vertices = TypedArray::<f32>::from(&[
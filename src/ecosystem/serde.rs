@@ -160,6 +160,15 @@ impl Serialize for Value {
             Value::Number( ref value ) => value.serialize( serializer ),
             Value::Symbol( _ ) => unimplemented!( "Serialization of symbols is unimplemented!" ),
             Value::String( ref value ) => serializer.serialize_str( value ),
+            Value::BigInt( ref value ) => {
+                if let Ok( value ) = i64::try_from( value ) {
+                    serializer.serialize_i64( value )
+                } else if let Ok( value ) = u64::try_from( value ) {
+                    serializer.serialize_u64( value )
+                } else {
+                    serializer.serialize_str( value.as_str() )
+                }
+            },
             Value::Reference( ref reference ) => {
                 if Array::instance_of( reference ) {
                     let array: Array = reference.try_into().unwrap();
@@ -751,6 +760,7 @@ impl Value {
                 }
             },
             Value::Symbol( _ ) => de::Unexpected::Other( "Symbol" ),
+            Value::BigInt( _ ) => de::Unexpected::Other( "BigInt" ),
             Value::String( ref value ) => de::Unexpected::Str( value ),
             Value::Reference( _ ) => de::Unexpected::Other( "reference to a JavaScript value" )
         }
@@ -783,6 +793,15 @@ impl< 'de > de::Deserializer< 'de > for Value {
             Value::Number( value ) => de::Deserializer::deserialize_any( value, visitor ),
             Value::Symbol( _ ) => unimplemented!( "Deserialization of symbols is unimplemented!" ),
             Value::String( value ) => visitor.visit_string( value ),
+            Value::BigInt( value ) => {
+                if let Ok( value ) = i64::try_from( &value ) {
+                    visitor.visit_i64( value )
+                } else if let Ok( value ) = u64::try_from( &value ) {
+                    visitor.visit_u64( value )
+                } else {
+                    visitor.visit_string( value.0 )
+                }
+            },
             Value::Reference( reference ) => {
                 if Array::instance_of( &reference ) {
                     let value: Array = reference.try_into().unwrap();
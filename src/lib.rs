@@ -196,6 +196,7 @@ pub use webcore::number::Number;
 pub use webcore::object::Object;
 pub use webcore::array::Array;
 pub use webcore::symbol::Symbol;
+pub use webcore::bigint::BigInt;
 
 pub use webcore::unsafe_typed_array::UnsafeTypedArray;
 pub use webcore::mutfn::Mut;
@@ -234,28 +235,38 @@ pub mod web {
         interval_buffered
     };
 
+    #[cfg(feature = "futures-support")]
+    pub use webapi::media_query_list::MediaQueryListChanges;
+
     pub use webapi::window::{
         Window,
         window
     };
     pub use webapi::document::{
         Document,
-        document
+        VisibilityState,
+        TitleBinding,
+        document,
+        set_document_title
     };
     pub use webapi::global::{
         set_timeout,
+        set_timeout_with_value,
         alert,
         confirm
     };
+    pub use webapi::json::{json_parse, json_stringify};
+    pub use webapi::uri::{encode_uri_component, decode_uri_component, encode_uri, decode_uri};
+    pub use webapi::base64::{btoa, atob};
     pub use webapi::cross_origin_setting::CrossOriginSetting;
     pub use webapi::date::Date;
     pub use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
-    pub use webapi::window::RequestAnimationFrameHandle;
+    pub use webapi::window::{RequestAnimationFrameHandle, IdleCallbackHandle, IdleDeadline};
     pub use webapi::node::{INode, Node, CloneKind, NodeType};
-    pub use webapi::element::{IElement, Element};
+    pub use webapi::element::{IElement, Element, InsertPosition, FullscreenOptions, FullscreenNavigationUi};
     pub use webapi::document_fragment::DocumentFragment;
     pub use webapi::text_node::TextNode;
-    pub use webapi::html_element::{IHtmlElement, HtmlElement, Rect};
+    pub use webapi::html_element::{IHtmlElement, HtmlElement, Rect, ScrollBehavior};
     pub use webapi::window_or_worker::IWindowOrWorker;
     pub use webapi::parent_node::IParentNode;
     pub use webapi::slotable::ISlotable;
@@ -263,28 +274,49 @@ pub mod web {
     pub use webapi::token_list::TokenList;
     pub use webapi::node_list::NodeList;
     pub use webapi::string_map::StringMap;
-    pub use webapi::storage::Storage;
+    pub use webapi::storage::{Storage, StorageIter};
     pub use webapi::location::Location;
     pub use webapi::array_buffer::ArrayBuffer;
+    pub use webapi::data_view::DataView;
     pub use webapi::typed_array::TypedArray;
     pub use webapi::file::File;
     pub use webapi::file_reader::{FileReader, FileReaderResult, FileReaderReadyState};
     pub use webapi::file_list::FileList;
-    pub use webapi::history::History;
+    pub use webapi::history::{History, ScrollRestoration};
     pub use webapi::web_socket::{WebSocket, SocketCloseCode, SocketBinaryType, SocketReadyState};
-    pub use webapi::rendering_context::{RenderingContext, CanvasRenderingContext2d, CanvasGradient, CanvasPattern, CanvasStyle, CompositeOperation, FillRule, ImageData, LineCap, LineJoin, Repetition, TextAlign, TextBaseline, TextMetrics};
+    pub use webapi::rendering_context::{RenderingContext, CanvasImageSource, CanvasRenderingContext2d, CanvasGradient, CanvasPattern, CanvasStyle, CompositeOperation, FillRule, ImageData, ImageSmoothingQuality, LineCap, LineJoin, PredefinedColorSpace, Repetition, TextAlign, TextBaseline, TextMetrics};
     pub use webapi::mutation_observer::{MutationObserver, MutationObserverHandle, MutationObserverInit, MutationRecord};
-    pub use webapi::xml_http_request::{XmlHttpRequest, XhrReadyState, XhrResponseType};
+    pub use webapi::intersection_observer::{IntersectionObserver, IntersectionObserverHandle, IntersectionObserverEntry};
+    pub use webapi::performance::{Performance, PerformanceObserver, PerformanceObserverHandle, PerformanceEntry};
+    pub use webapi::resize_observer::{ResizeObserver, ResizeObserverHandle, ResizeObserverEntry, ResizeObserverSize, ResizeBox};
+    pub use webapi::xml_http_request::{XmlHttpRequest, XmlHttpRequestUpload, XhrReadyState, XhrResponseType};
     pub use webapi::blob::{IBlob, Blob};
+    pub use webapi::navigator::{Navigator, Clipboard};
+    pub use webapi::battery::BatteryManager;
+    pub use webapi::screen::{Screen, ScreenOrientation, OrientationType, OrientationLockType};
+    pub use webapi::notification::{Notification, NotificationOptions, NotificationPermission};
+    pub use webapi::text_codec::{TextDecoder, TextEncoder};
+    pub use webapi::audio::{AudioContext, IAudioNode, AudioNode, IAudioScheduledSourceNode, AudioDestinationNode, OscillatorNode, GainNode, AudioBufferSourceNode, AudioBuffer};
+    pub use webapi::streams::{ReadableStream, ReadableStreamDefaultReader, WritableStream, WritableStreamDefaultWriter, TransformStream};
     pub use webapi::html_collection::HtmlCollection;
     pub use webapi::child_node::IChildNode;
-    pub use webapi::gamepad::{Gamepad, GamepadButton, GamepadMappingType};
+    pub use webapi::gamepad::{Gamepad, GamepadButton, GamepadMappingType, GamepadPose};
     pub use webapi::touch::{Touch, TouchType};
     pub use webapi::selection::Selection;
     pub use webapi::shadow_root::{ShadowRootMode, ShadowRoot};
     pub use webapi::html_elements::SlotContentKind;
+    pub use webapi::html_elements::IMediaElement;
     pub use webapi::form_data::{FormData, FormDataEntry};
     pub use webapi::window_or_worker::TimeoutHandle;
+    pub use webapi::media_query_list::{MediaQueryList, prefers_dark_mode, on_color_scheme_change};
+    pub use webapi::css_style_declaration::CssStyleDeclaration;
+    pub use webapi::weak_collections::{WeakMap, WeakSet};
+    pub use webapi::router::Router;
+    pub use webapi::url::{Url, UrlSearchParams, UrlSearchParamsIter};
+    pub use webapi::fetch::{RequestInit, RequestInitBuilder, RequestMode, RequestCredentials, RequestCache, Response};
+    pub use webapi::headers::{Headers, HeadersIter};
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub use webapi::fetch::fetch;
 
     /// A module containing error types.
     pub mod error {
@@ -300,18 +332,26 @@ pub mod web {
             SecurityError,
             SyntaxError,
             InvalidCharacterError,
-            AbortError
+            AbortError,
+            ConstraintError,
+            QuotaExceededError
         };
 
         pub use webapi::error::{
             IError,
             Error,
-            TypeError
+            TypeError,
+            RangeError
         };
 
-        pub use webapi::rendering_context::{AddColorStopError, DrawImageError, GetImageDataError};
+        pub use webapi::rendering_context::{AddColorStopError, DrawImageError, GetImageDataError, EllipseError};
         pub use webapi::html_elements::UnknownValueError;
         pub use webapi::xml_http_request::XhrSetResponseTypeError;
+        pub use webapi::document::{AdoptNodeError, CreateElementNsError};
+        pub use webapi::element::InsertAdjacentError;
+        pub use webapi::token_list::TokenError;
+        #[cfg(feature = "serde")]
+        pub use webapi::storage::InsertSerdeError;
     }
 
     /// A module containing HTML DOM elements.
@@ -324,6 +364,9 @@ pub mod web {
         pub use webapi::html_elements::OptionElement;
         pub use webapi::html_elements::TemplateElement;
         pub use webapi::html_elements::SlotElement;
+        pub use webapi::html_elements::MediaElement;
+        pub use webapi::html_elements::AudioElement;
+        pub use webapi::html_elements::VideoElement;
     }
 
     /// A module containing JavaScript DOM events.
@@ -341,6 +384,16 @@ pub mod web {
             EventPhase
         };
 
+        pub use webapi::events::custom::CustomEvent;
+
+        pub use webapi::events::error::{
+            ErrorEvent,
+            IPromiseRejectionEvent,
+            PromiseRejectionEvent,
+            UnhandledRejectionEvent,
+            RejectionHandledEvent
+        };
+
         pub use webapi::events::mouse::{
             IMouseEvent,
             ClickEvent,
@@ -404,7 +457,8 @@ pub mod web {
             LoadEndEvent,
             ProgressLoadEvent,
             ProgressAbortEvent,
-            ProgressErrorEvent
+            ProgressErrorEvent,
+            TimeoutEvent
         };
 
         pub use webapi::events::socket::{
@@ -431,13 +485,18 @@ pub mod web {
             InputEvent,
             ReadyStateChangeEvent,
             SubmitEvent,
-            SelectionChangeEvent
+            SelectionChangeEvent,
+            OnlineEvent,
+            OfflineEvent,
+            VisibilityChangeEvent
         };
 
         pub use webapi::events::focus::{
             IFocusEvent,
             FocusEvent,
-            BlurEvent
+            BlurEvent,
+            FocusInEvent,
+            FocusOutEvent
         };
 
         pub use webapi::events::gamepad::{
@@ -461,11 +520,51 @@ pub mod web {
             EffectAllowed,
             DropEffect,
             DataTransferItemList,
+            DataTransferItemIter,
             DataTransferItem,
             DataTransferItemKind,
         };
 
         pub use webapi::events::slot::SlotChangeEvent;
+
+        pub use webapi::events::media::{
+            PlayEvent,
+            PauseEvent,
+            EndedEvent,
+            TimeUpdateEvent,
+            CanPlayEvent,
+        };
+
+        pub use webapi::events::clipboard::{
+            IClipboardEvent,
+            ClipboardRelatedEvent,
+            CopyEvent,
+            CutEvent,
+            PasteEvent,
+        };
+
+        pub use webapi::notification::NotificationClickEvent;
+    }
+
+    /// A module containing bindings to the Web Crypto API.
+    pub mod crypto {
+        pub use webapi::crypto::{get_random_values, random_uuid};
+    }
+
+    /// A module containing non-blocking alternatives to the native dialogs.
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub mod dialog {
+        pub use webapi::dialog::confirm_async;
+    }
+
+    /// A module containing the XML namespaces of elements which can be created
+    /// with [Document::create_element_ns](../struct.Document.html#method.create_element_ns).
+    pub mod namespace {
+        pub use webapi::document::{
+            HTML_NAMESPACE,
+            SVG_NAMESPACE,
+            MATHML_NAMESPACE
+        };
     }
 
     #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
@@ -504,7 +603,10 @@ pub mod traits {
         INode,
         IElement,
         IHtmlElement,
+        IMediaElement,
         IBlob,
+        IAudioNode,
+        IAudioScheduledSourceNode,
 
         // Mixins.
         IWindowOrWorker,
@@ -512,6 +614,7 @@ pub mod traits {
         INonElementParentNode,
         IChildNode,
         ISlotable,
+        CanvasImageSource,
     };
 
     #[doc(hidden)]
@@ -532,6 +635,7 @@ pub mod traits {
         IFocusEvent,
         IDragEvent,
         ITouchEvent,
+        IClipboardEvent,
     };
 
     #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
@@ -0,0 +1,487 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::dom_exception::{InvalidStateError, NotSupportedError};
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::array_buffer::ArrayBuffer;
+
+/// An audio-processing graph built from audio nodes linked together.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext)
+// https://webaudio.github.io/web-audio-api/#AudioContext
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioContext")]
+#[reference(subclass_of(EventTarget))]
+pub struct AudioContext( Reference );
+
+impl IEventTarget for AudioContext {}
+
+impl AudioContext {
+    /// Creates a new `AudioContext`.
+    ///
+    /// Browsers may start the context in a suspended state until the user interacts with the page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/AudioContext)
+    // https://webaudio.github.io/web-audio-api/#dom-audiocontext-audiocontext
+    pub fn new() -> Self {
+        js!(
+            var AudioContext = window.AudioContext || window.webkitAudioContext;
+            return new AudioContext();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time in seconds of the audio hardware clock, starting at zero
+    /// when the context is created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/currentTime)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-currenttime
+    pub fn current_time( &self ) -> f64 {
+        js!(
+            return @{self}.currentTime;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the sample rate, in samples per second, used by all nodes in this context.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/sampleRate)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-samplerate
+    pub fn sample_rate( &self ) -> f32 {
+        let sample_rate: f64 = js!(
+            return @{self}.sampleRate;
+        ).try_into().unwrap();
+
+        sample_rate as f32
+    }
+
+    /// Returns the final destination of all audio in the context, usually the speakers.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/destination)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-destination
+    pub fn destination( &self ) -> AudioDestinationNode {
+        js!(
+            return @{self}.destination;
+        ).try_into().unwrap()
+    }
+
+    /// Creates an [OscillatorNode](struct.OscillatorNode.html) which generates a periodic waveform.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createOscillator)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createoscillator
+    pub fn create_oscillator( &self ) -> OscillatorNode {
+        js!(
+            return @{self}.createOscillator();
+        ).try_into().unwrap()
+    }
+
+    /// Creates an [AudioBufferSourceNode](struct.AudioBufferSourceNode.html) which plays
+    /// the audio data of an [AudioBuffer](struct.AudioBuffer.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createBufferSource)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffersource
+    pub fn create_buffer_source( &self ) -> AudioBufferSourceNode {
+        js!(
+            return @{self}.createBufferSource();
+        ).try_into().unwrap()
+    }
+
+    /// Creates a new empty [AudioBuffer](struct.AudioBuffer.html) with the given number of channels,
+    /// length in sample-frames and sample rate.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createBuffer)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffer
+    pub fn create_buffer( &self, number_of_channels: u32, length: u32, sample_rate: f32 ) -> Result< AudioBuffer, NotSupportedError > {
+        js_try!(
+            return @{self}.createBuffer( @{number_of_channels}, @{length}, @{sample_rate} );
+        ).unwrap()
+    }
+
+    /// Asynchronously decodes audio file data, e.g. the contents of a WAV, MP3 or Ogg file,
+    /// into an [AudioBuffer](struct.AudioBuffer.html).
+    ///
+    /// The `data` buffer is detached by the browser and can't be used after this call.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/decodeAudioData)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-decodeaudiodata
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn decode_audio_data( &self, data: &ArrayBuffer ) -> PromiseFuture< AudioBuffer > {
+        js!(
+            var context = @{self};
+            var data = @{data};
+            return new Promise( function( resolve, reject ) {
+                // Older browsers only support the callback form.
+                var promise = context.decodeAudioData( data, resolve, reject );
+                if( promise ) {
+                    promise.catch( function() {} );
+                }
+            });
+        ).try_into().unwrap()
+    }
+
+    /// Creates a [GainNode](struct.GainNode.html) which controls the volume of its input.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/BaseAudioContext/createGain)
+    // https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-creategain
+    pub fn create_gain( &self ) -> GainNode {
+        js!(
+            return @{self}.createGain();
+        ).try_into().unwrap()
+    }
+}
+
+impl Default for AudioContext {
+    #[inline]
+    fn default() -> Self {
+        AudioContext::new()
+    }
+}
+
+/// The `IAudioNode` interface represents an audio-processing module, like an
+/// audio source, an audio destination or an intermediate processing module.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode)
+// https://webaudio.github.io/web-audio-api/#AudioNode
+pub trait IAudioNode: IEventTarget {
+    /// Connects the output of this node to the input of the `destination` node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode/connect)
+    // https://webaudio.github.io/web-audio-api/#dom-audionode-connect
+    fn connect< T: IAudioNode >( &self, destination: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.connect( @{destination.as_ref()} );
+        }
+    }
+
+    /// Disconnects all outgoing connections of this node.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode/disconnect)
+    // https://webaudio.github.io/web-audio-api/#dom-audionode-disconnect
+    fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.disconnect();
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IAudioNode](trait.IAudioNode.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioNode)
+// https://webaudio.github.io/web-audio-api/#AudioNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioNode")]
+#[reference(subclass_of(EventTarget))]
+pub struct AudioNode( Reference );
+
+impl IEventTarget for AudioNode {}
+impl IAudioNode for AudioNode {}
+
+/// The `IAudioScheduledSourceNode` interface represents an audio source node
+/// which can be started and stopped, like an [OscillatorNode](struct.OscillatorNode.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioScheduledSourceNode)
+// https://webaudio.github.io/web-audio-api/#AudioScheduledSourceNode
+pub trait IAudioScheduledSourceNode: IAudioNode {
+    /// Starts playing the source immediately.
+    ///
+    /// Returns an `InvalidStateError` if the source was already started;
+    /// a source can only be started once.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioScheduledSourceNode/start)
+    // https://webaudio.github.io/web-audio-api/#dom-audioscheduledsourcenode-start
+    fn start( &self ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.start();
+        ).unwrap()
+    }
+
+    /// Stops playing the source immediately.
+    ///
+    /// Returns an `InvalidStateError` if the source wasn't started yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioScheduledSourceNode/stop)
+    // https://webaudio.github.io/web-audio-api/#dom-audioscheduledsourcenode-stop
+    fn stop( &self ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.stop();
+        ).unwrap()
+    }
+}
+
+/// The final destination of an audio graph, usually the speakers.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioDestinationNode)
+// https://webaudio.github.io/web-audio-api/#AudioDestinationNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioDestinationNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct AudioDestinationNode( Reference );
+
+impl IEventTarget for AudioDestinationNode {}
+impl IAudioNode for AudioDestinationNode {}
+
+/// An audio source which generates a periodic waveform, like a sine wave.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OscillatorNode)
+// https://webaudio.github.io/web-audio-api/#OscillatorNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "OscillatorNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct OscillatorNode( Reference );
+
+impl IEventTarget for OscillatorNode {}
+impl IAudioNode for OscillatorNode {}
+impl IAudioScheduledSourceNode for OscillatorNode {}
+
+impl OscillatorNode {
+    /// Returns the frequency of the oscillation in hertz.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OscillatorNode/frequency)
+    // https://webaudio.github.io/web-audio-api/#dom-oscillatornode-frequency
+    pub fn frequency( &self ) -> f32 {
+        let frequency: f64 = js!(
+            return @{self}.frequency.value;
+        ).try_into().unwrap();
+
+        frequency as f32
+    }
+
+    /// Sets the frequency of the oscillation in hertz.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/OscillatorNode/frequency)
+    // https://webaudio.github.io/web-audio-api/#dom-oscillatornode-frequency
+    pub fn set_frequency( &self, frequency: f32 ) {
+        js! { @(no_return)
+            @{self}.frequency.value = @{frequency};
+        }
+    }
+}
+
+/// A node which changes the volume of its input.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GainNode)
+// https://webaudio.github.io/web-audio-api/#GainNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "GainNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct GainNode( Reference );
+
+impl IEventTarget for GainNode {}
+impl IAudioNode for GainNode {}
+
+impl GainNode {
+    /// Returns the amount of gain to apply, where `1.0` leaves the volume unchanged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GainNode/gain)
+    // https://webaudio.github.io/web-audio-api/#dom-gainnode-gain
+    pub fn gain( &self ) -> f32 {
+        let gain: f64 = js!(
+            return @{self}.gain.value;
+        ).try_into().unwrap();
+
+        gain as f32
+    }
+
+    /// Sets the amount of gain to apply, where `1.0` leaves the volume unchanged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GainNode/gain)
+    // https://webaudio.github.io/web-audio-api/#dom-gainnode-gain
+    pub fn set_gain( &self, gain: f32 ) {
+        js! { @(no_return)
+            @{self}.gain.value = @{gain};
+        }
+    }
+}
+
+/// An audio source which plays the audio data of an [AudioBuffer](struct.AudioBuffer.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode)
+// https://webaudio.github.io/web-audio-api/#AudioBufferSourceNode
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioBufferSourceNode")]
+#[reference(subclass_of(EventTarget, AudioNode))]
+pub struct AudioBufferSourceNode( Reference );
+
+impl IEventTarget for AudioBufferSourceNode {}
+impl IAudioNode for AudioBufferSourceNode {}
+impl IAudioScheduledSourceNode for AudioBufferSourceNode {}
+
+impl AudioBufferSourceNode {
+    /// Returns the audio data which will be played, if any.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/buffer)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-buffer
+    pub fn buffer( &self ) -> Option< AudioBuffer > {
+        js!(
+            return @{self}.buffer;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the audio data which will be played.
+    ///
+    /// Returns an `InvalidStateError` if a buffer was already set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBufferSourceNode/buffer)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffersourcenode-buffer
+    pub fn set_buffer( &self, buffer: &AudioBuffer ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.buffer = @{buffer};
+        ).unwrap()
+    }
+}
+
+/// A short audio asset residing in memory, e.g. a decoded sound effect.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer)
+// https://webaudio.github.io/web-audio-api/#AudioBuffer
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "AudioBuffer")]
+pub struct AudioBuffer( Reference );
+
+impl AudioBuffer {
+    /// Returns the duration of the audio data in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/duration)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-duration
+    pub fn duration( &self ) -> f64 {
+        js!(
+            return @{self}.duration;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the length of the audio data in sample-frames.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/length)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-length
+    pub fn len( &self ) -> u32 {
+        js!(
+            return @{self}.length;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the audio data has no sample-frames.
+    #[inline]
+    pub fn is_empty( &self ) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the sample rate of the audio data in samples per second.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/sampleRate)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-samplerate
+    pub fn sample_rate( &self ) -> f32 {
+        let sample_rate: f64 = js!(
+            return @{self}.sampleRate;
+        ).try_into().unwrap();
+
+        sample_rate as f32
+    }
+
+    /// Returns the number of discrete audio channels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/AudioBuffer/numberOfChannels)
+    // https://webaudio.github.io/web-audio-api/#dom-audiobuffer-numberofchannels
+    pub fn number_of_channels( &self ) -> u32 {
+        js!(
+            return @{self}.numberOfChannels;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
+
+    #[test]
+    fn test_oscillator_graph() {
+        let context = AudioContext::new();
+        assert!( context.sample_rate() > 0.0 );
+        assert!( context.current_time() >= 0.0 );
+
+        let oscillator = context.create_oscillator();
+        let gain = context.create_gain();
+        oscillator.connect( &gain );
+        gain.connect( &context.destination() );
+
+        oscillator.set_frequency( 440.0 );
+        assert_eq!( oscillator.frequency(), 440.0 );
+        gain.set_gain( 0.0 );
+        assert_eq!( gain.gain(), 0.0 );
+
+        assert!( oscillator.stop().is_err() );
+        oscillator.start().unwrap();
+        assert!( oscillator.start().is_err() );
+        oscillator.stop().unwrap();
+
+        oscillator.disconnect();
+        gain.disconnect();
+    }
+
+    #[test]
+    fn test_buffer_source() {
+        let context = AudioContext::new();
+        assert!( context.create_buffer( 0, 10, 44100.0 ).is_err() );
+
+        let buffer = context.create_buffer( 2, 22050, 44100.0 ).unwrap();
+        assert_eq!( buffer.number_of_channels(), 2 );
+        assert_eq!( buffer.len(), 22050 );
+        assert_eq!( buffer.sample_rate(), 44100.0 );
+        assert_eq!( buffer.duration(), 0.5 );
+
+        let source = context.create_buffer_source();
+        assert!( source.buffer().is_none() );
+        source.set_buffer( &buffer ).unwrap();
+        assert_eq!( source.buffer(), Some( buffer.clone() ) );
+        assert!( source.set_buffer( &buffer ).is_err() );
+
+        source.connect( &context.destination() );
+        source.start().unwrap();
+        source.stop().unwrap();
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_decode_audio_data< F: FnOnce() >( done: F ) {
+        use webcore::promise_future::spawn_local;
+        use futures_util::FutureExt;
+
+        // A 16-bit mono PCM WAV file holding 800 silent sample-frames at 8kHz.
+        let data: ArrayBuffer = js!(
+            var frames = 800;
+            var buffer = new ArrayBuffer( 44 + frames * 2 );
+            var view = new DataView( buffer );
+            var writeString = function( offset, string ) {
+                for( var i = 0; i < string.length; ++i ) {
+                    view.setUint8( offset + i, string.charCodeAt( i ) );
+                }
+            };
+
+            writeString( 0, "RIFF" );
+            view.setUint32( 4, 36 + frames * 2, true );
+            writeString( 8, "WAVE" );
+            writeString( 12, "fmt " );
+            view.setUint32( 16, 16, true );
+            view.setUint16( 20, 1, true );
+            view.setUint16( 22, 1, true );
+            view.setUint32( 24, 8000, true );
+            view.setUint32( 28, 8000 * 2, true );
+            view.setUint16( 32, 2, true );
+            view.setUint16( 34, 16, true );
+            writeString( 36, "data" );
+            view.setUint32( 40, frames * 2, true );
+            return buffer;
+        ).try_into().unwrap();
+
+        let context = AudioContext::new();
+        spawn_local( context.decode_audio_data( &data ).map( move |result| {
+            let buffer = result.unwrap();
+            assert!( buffer.duration() > 0.0 );
+            assert!( !buffer.is_empty() );
+            done();
+        }));
+    }
+}
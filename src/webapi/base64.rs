@@ -0,0 +1,48 @@
+use webapi::dom_exception::InvalidCharacterError;
+
+/// Encodes a string into base64.
+///
+/// Every character of `data` is treated as a single byte, so this only works with
+/// Latin-1 strings, i.e. strings where every character is in the `U+0000` to `U+00FF`
+/// range; an `InvalidCharacterError` is returned otherwise. To encode arbitrary
+/// Unicode text first convert it into UTF-8 bytes, e.g. with
+/// [TextEncoder](struct.TextEncoder.html), and map every byte to a `char`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/btoa)
+// https://html.spec.whatwg.org/#dom-btoa
+pub fn btoa( data: &str ) -> Result< String, InvalidCharacterError > {
+    js_try!(
+        return btoa( @{data} );
+    ).unwrap()
+}
+
+/// Decodes a base64 encoded string.
+///
+/// The result is a Latin-1 string where every character represents a single
+/// decoded byte. Returns an `InvalidCharacterError` if `data` isn't valid base64.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/atob)
+// https://html.spec.whatwg.org/#dom-atob
+pub fn atob( data: &str ) -> Result< String, InvalidCharacterError > {
+    js_try!(
+        return atob( @{data} );
+    ).unwrap()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!( btoa( "Hello, world!" ).unwrap(), "SGVsbG8sIHdvcmxkIQ==" );
+        assert_eq!( atob( "SGVsbG8sIHdvcmxkIQ==" ).unwrap(), "Hello, world!" );
+        assert_eq!( atob( &btoa( "\u{00FF}\u{0000}" ).unwrap() ).unwrap(), "\u{00FF}\u{0000}" );
+    }
+
+    #[test]
+    fn test_invalid_characters() {
+        assert!( btoa( "€" ).is_err() );
+        assert!( atob( "not base64!" ).is_err() );
+    }
+}
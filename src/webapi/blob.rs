@@ -5,6 +5,7 @@ use webcore::try_from::TryInto;
 use webcore::reference_type::ReferenceType;
 use webcore::number::Number;
 use webcore::optional_arg::OptionalArg;
+use webapi::streams::ReadableStream;
 
 // https://w3c.github.io/FileAPI/#ref-for-dfn-slice
 fn slice_blob< T, U >( blob: &T, range: U, content_type: Option< &str > ) -> Blob
@@ -77,6 +78,16 @@ pub trait IBlob: ReferenceType {
     {
         slice_blob(self, range, Some(content_type))
     }
+
+    /// Returns a [ReadableStream](struct.ReadableStream.html) which reads the
+    /// contents of the `Blob` in chunks, without loading all of it into memory.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Blob/stream)
+    // https://w3c.github.io/FileAPI/#dom-blob-stream
+    fn stream( &self ) -> ReadableStream {
+        let reference = self.as_ref();
+        js!( return @{reference}.stream(); ).try_into().unwrap()
+    }
 }
 
 /// A reference to a JavaScript object which implements the [IBlob](trait.IBlob.html)
@@ -101,3 +112,38 @@ impl Blob {
         ).try_into().unwrap()
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    fn hello_world() -> Blob {
+        js!(
+            return new Blob( [ "hello world" ], { type: "text/plain" } );
+        ).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_len_and_mime() {
+        let blob = hello_world();
+        assert_eq!( blob.len(), 11 );
+        assert_eq!( blob.mime(), Some( "text/plain".to_owned() ) );
+
+        assert_eq!( Blob::new().len(), 0 );
+        assert_eq!( Blob::new().mime(), None );
+    }
+
+    #[test]
+    fn test_slice() {
+        let blob = hello_world();
+        assert_eq!( blob.slice( 0..5 ).len(), 5 );
+        assert_eq!( blob.slice( 6.. ).len(), 5 );
+        assert_eq!( blob.slice( ..=4 ).len(), 5 );
+        assert_eq!( blob.slice( .. ).len(), 11 );
+        assert_eq!( blob.slice( 0..5 ).mime(), None );
+
+        let chunk = blob.slice_with_content_type( 6..11, "text/x-chunk" );
+        assert_eq!( chunk.len(), 5 );
+        assert_eq!( chunk.mime(), Some( "text/x-chunk".to_owned() ) );
+    }
+}
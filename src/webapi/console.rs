@@ -68,8 +68,46 @@ macro_rules! __internal_console_unsafe {
 /// This is exactly the same as `log`, except it prints an error message rather than a normal message.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/error)
+///
+/// ## dir
+///
+/// Displays an interactive listing of the properties of one value:
+///
+/// ```rust
+/// console!(dir, document().body());
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/dir)
+///
+/// ## trace
+///
+/// Prints a stack trace, optionally preceded by any number of values
+/// in the same way as `log`:
+///
+/// ```rust
+/// console!(trace);
+/// console!(trace, "reached the end of the frame");
+/// ```
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Console/trace)
 #[macro_export]
 macro_rules! console {
     ( log, $( $args:expr ),+ ) => { $crate::__internal_console_unsafe!( log, $( $args ),+ ) };
     ( error, $( $args:expr ),+ ) => { $crate::__internal_console_unsafe!( error, $( $args ),+ ) };
+    ( dir, $arg:expr ) => { $crate::__internal_console_unsafe!( dir, $arg ) };
+    ( trace ) => { $crate::__internal_console_unsafe!( trace ) };
+    ( trace, $( $args:expr ),+ ) => { $crate::__internal_console_unsafe!( trace, $( $args ),+ ) };
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::document::document;
+
+    #[test]
+    fn test_dir_and_trace() {
+        console!(dir, document().body().unwrap());
+        console!(dir, vec![1, 2, 3]);
+        console!(trace);
+        console!(trace, "trace with", document().body().unwrap());
+    }
 }
@@ -0,0 +1,95 @@
+use webcore::try_from::TryInto;
+use webapi::typed_array::TypedArray;
+
+// This is the maximum number of bytes `getRandomValues` can fill in one call.
+const MAX_RANDOM_VALUES_LENGTH: usize = 65536;
+
+/// Fills the given buffer with cryptographically strong random bytes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Crypto/getRandomValues)
+// https://w3c.github.io/webcrypto/#Crypto-method-getRandomValues
+pub fn get_random_values( buffer: &mut [u8] ) {
+    for chunk in buffer.chunks_mut( MAX_RANDOM_VALUES_LENGTH ) {
+        // The bytes are generated on the JavaScript side and copied over,
+        // since the Rust buffer can't be safely written to from there.
+        let random: TypedArray< u8 > = js!(
+            return crypto.getRandomValues( new Uint8Array( @{chunk.len() as u32} ) );
+        ).try_into().unwrap();
+
+        chunk.copy_from_slice( &random.to_vec() );
+    }
+}
+
+/// Returns a new randomly generated version 4 UUID, e.g. `"36b8f84d-df4e-4d49-b662-bcde71a8764f"`.
+///
+/// Uses `crypto.randomUUID` where available, and otherwise builds
+/// the UUID from [get_random_values](fn.get_random_values.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Crypto/randomUUID)
+// https://w3c.github.io/webcrypto/#Crypto-method-randomUUID
+pub fn random_uuid() -> String {
+    let uuid: Option< String > = js!(
+        return crypto.randomUUID ? crypto.randomUUID() : null;
+    ).try_into().unwrap();
+
+    uuid.unwrap_or_else( fallback_random_uuid )
+}
+
+fn fallback_random_uuid() -> String {
+    let mut bytes = [0; 16];
+    get_random_values( &mut bytes );
+    bytes[ 6 ] = (bytes[ 6 ] & 0x0f) | 0x40;
+    bytes[ 8 ] = (bytes[ 8 ] & 0x3f) | 0x80;
+
+    let mut uuid = String::with_capacity( 36 );
+    for (index, byte) in bytes.iter().enumerate() {
+        if index == 4 || index == 6 || index == 8 || index == 10 {
+            uuid.push( '-' );
+        }
+        uuid.push_str( &format!( "{:02x}", byte ) );
+    }
+
+    uuid
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_random_values() {
+        let mut buffer = vec![ 0; MAX_RANDOM_VALUES_LENGTH + 16 ];
+        get_random_values( &mut buffer );
+        // The chance of either chunk being all zeroes is negligible.
+        assert!( buffer[ ..MAX_RANDOM_VALUES_LENGTH ].iter().any( |&byte| byte != 0 ) );
+        assert!( buffer[ MAX_RANDOM_VALUES_LENGTH.. ].iter().any( |&byte| byte != 0 ) );
+    }
+
+    fn assert_uuid_v4( uuid: &str ) {
+        assert_eq!( uuid.len(), 36, "{}", uuid );
+        for (index, character) in uuid.chars().enumerate() {
+            match index {
+                8 | 13 | 18 | 23 => assert_eq!( character, '-', "{}", uuid ),
+                _ => assert!( character.is_digit( 16 ) && !character.is_uppercase(), "{}", uuid )
+            }
+        }
+
+        assert_eq!( &uuid[ 14..15 ], "4", "{}", uuid );
+        assert!( [ "8", "9", "a", "b" ].contains( &&uuid[ 19..20 ] ), "{}", uuid );
+    }
+
+    #[test]
+    fn test_random_uuid() {
+        let uuid = random_uuid();
+        assert_uuid_v4( &uuid );
+        assert_ne!( uuid, random_uuid() );
+    }
+
+    #[test]
+    fn test_fallback_random_uuid() {
+        for _ in 0..16 {
+            assert_uuid_v4( &fallback_random_uuid() );
+        }
+        assert_ne!( fallback_random_uuid(), fallback_random_uuid() );
+    }
+}
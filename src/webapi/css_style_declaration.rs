@@ -0,0 +1,140 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::dom_exception::NoModificationAllowedError;
+
+/// A set of CSS properties and their values, like the computed style of an
+/// element returned by [Window::get_computed_style](struct.Window.html#method.get_computed_style).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration)
+// https://drafts.csswg.org/cssom/#the-cssstyledeclaration-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CSSStyleDeclaration")]
+pub struct CssStyleDeclaration( Reference );
+
+impl CssStyleDeclaration {
+    /// Returns the value of the given property, e.g. `get_property_value("width")`
+    /// may return `"120px"`. Returns an empty string if the property isn't set.
+    ///
+    /// The property name uses the CSS spelling (`"background-color"`, not `"backgroundColor"`).
+    /// Custom properties are looked up by their full name, including the leading `--`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/getPropertyValue)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-getpropertyvalue
+    pub fn get_property_value( &self, name: &str ) -> String {
+        js!(
+            return @{self}.getPropertyValue( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Sets the value of the given property, e.g. `set_property("width", "120px")`
+    /// or `set_property("--accent", "#ff0000")` for a custom property.
+    ///
+    /// Invalid values of standard properties are silently ignored. Fails with a
+    /// `NoModificationAllowedError` if the declaration is read-only, like a computed style.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-setproperty
+    pub fn set_property( &self, name: &str, value: &str ) -> Result< (), NoModificationAllowedError > {
+        js_try!( @(no_return)
+            @{self}.setProperty( @{name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Removes the given property and returns its previous value.
+    ///
+    /// Fails with a `NoModificationAllowedError` if the declaration is read-only, like a computed style.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/removeProperty)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-removeproperty
+    pub fn remove_property( &self, name: &str ) -> Result< String, NoModificationAllowedError > {
+        js_try!(
+            return @{self}.removeProperty( @{name} );
+        ).unwrap()
+    }
+
+    /// Returns the number of properties in the declaration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/length)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-length
+    pub fn len( &self ) -> u32 {
+        js!(
+            return @{self}.length;
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the declaration has no properties.
+    #[inline]
+    pub fn is_empty( &self ) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the name of the property at the given index, or `None` if the index is out of bounds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/item)
+    // https://drafts.csswg.org/cssom/#dom-cssstyledeclaration-item
+    pub fn item( &self, index: u32 ) -> Option< String > {
+        js!(
+            var name = @{self}.item( @{index} );
+            return name === "" ? null : name;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::document::document;
+    use webapi::window::window;
+    use webapi::element::IElement;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_computed_style() {
+        let parent = document().create_element( "div" ).unwrap();
+        parent.set_attribute( "style", "display: flex; width: 300px" ).unwrap();
+        let child = document().create_element( "div" ).unwrap();
+        child.set_attribute( "style", "flex: 1" ).unwrap();
+        parent.append_child( &child );
+        document().body().unwrap().append_child( &parent );
+
+        let style = window().get_computed_style( &child, None );
+        assert_eq!( style.get_property_value( "width" ), "300px" );
+        assert_eq!( style.get_property_value( "not-a-property" ), "" );
+
+        assert!( style.len() > 0 );
+        assert!( !style.is_empty() );
+        assert!( style.item( 0 ).is_some() );
+        assert_eq!( style.item( style.len() ), None );
+
+        let before = window().get_computed_style( &child, Some( "::before" ) );
+        assert_eq!( before.get_property_value( "content" ), "none" );
+
+        document().body().unwrap().remove_child( &parent ).unwrap();
+    }
+
+    #[test]
+    fn test_custom_property() {
+        use webapi::html_element::{IHtmlElement, HtmlElement};
+        use webcore::try_from::TryInto;
+
+        let element: HtmlElement = document().create_element( "div" ).unwrap().try_into().unwrap();
+        document().body().unwrap().append_child( &element );
+        assert!( element.style().is_empty() );
+
+        element.set_css_var( "accent", "#ff0000" );
+        assert!( !element.style().is_empty() );
+        assert_eq!( element.style().get_property_value( "--accent" ), "#ff0000" );
+        assert_eq!( window().get_computed_style( &element, None ).get_property_value( "--accent" ).trim(), "#ff0000" );
+
+        element.style().set_property( "--accent", "blue" ).unwrap();
+        element.style().set_property( "color", "var(--accent)" ).unwrap();
+        assert_eq!( window().get_computed_style( &element, None ).get_property_value( "color" ), "rgb(0, 0, 255)" );
+
+        assert_eq!( element.style().remove_property( "--accent" ).unwrap(), "blue" );
+        assert_eq!( element.style().get_property_value( "--accent" ), "" );
+
+        let computed = window().get_computed_style( &element, None );
+        assert!( computed.set_property( "--accent", "red" ).is_err() );
+
+        document().body().unwrap().remove_child( &element ).unwrap();
+    }
+}
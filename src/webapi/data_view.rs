@@ -0,0 +1,209 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::array_buffer::ArrayBuffer;
+
+/// The `DataView` view provides a low-level interface for reading and writing
+/// multiple number types in an [ArrayBuffer](struct.ArrayBuffer.html), with
+/// explicit control over their endianness.
+///
+/// All of the accessors panic if the value at the given byte offset doesn't
+/// fit into the view.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-dataview-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "DataView")]
+pub struct DataView( Reference );
+
+macro_rules! accessors {
+    ($( $element_type:ty, $getter:ident, $setter:ident, $js_getter:ident, $js_setter:ident, $getter_docs:expr, $setter_docs:expr; )*) => {
+        $(
+            #[doc = "Reads the `"]
+            #[doc = stringify!($element_type)]
+            #[doc = "` at the given byte offset."]
+            #[doc = ""]
+            #[doc = concat!( "[(JavaScript docs)](", $getter_docs, ")" )]
+            pub fn $getter( &self, byte_offset: u32, little_endian: bool ) -> $element_type {
+                js!(
+                    return @{self}.$js_getter( @{byte_offset}, @{little_endian} );
+                ).try_into().unwrap()
+            }
+
+            #[doc = "Writes the `"]
+            #[doc = stringify!($element_type)]
+            #[doc = "` at the given byte offset."]
+            #[doc = ""]
+            #[doc = concat!( "[(JavaScript docs)](", $setter_docs, ")" )]
+            pub fn $setter( &self, byte_offset: u32, value: $element_type, little_endian: bool ) {
+                js! { @(no_return)
+                    @{self}.$js_setter( @{byte_offset}, @{value}, @{little_endian} );
+                }
+            }
+        )*
+    }
+}
+
+impl DataView {
+    /// Creates a view of `byte_length` bytes of the given buffer, starting at `byte_offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the view would extend past the end of the buffer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/DataView)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview-buffer-byteoffset-bytelength
+    pub fn new( buffer: &ArrayBuffer, byte_offset: u32, byte_length: u32 ) -> Self {
+        assert!(
+            byte_offset as u64 + byte_length as u64 <= buffer.len(),
+            "a view of {} bytes at byte offset {} doesn't fit into a buffer of {} bytes", byte_length, byte_offset, buffer.len()
+        );
+
+        js!(
+            return new DataView( @{buffer}, @{byte_offset}, @{byte_length} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the buffer referenced by this view.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/buffer)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.buffer
+    pub fn buffer( &self ) -> ArrayBuffer {
+        js!( return @{self}.buffer; ).try_into().unwrap()
+    }
+
+    /// Returns the offset of this view from the start of its buffer, in bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteOffset)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.byteoffset
+    pub fn byte_offset( &self ) -> u32 {
+        js!( return @{self}.byteOffset; ).try_into().unwrap()
+    }
+
+    /// Returns the length of this view, in bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/byteLength)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-get-dataview.prototype.bytelength
+    pub fn byte_length( &self ) -> u32 {
+        js!( return @{self}.byteLength; ).try_into().unwrap()
+    }
+
+    /// Reads the `i8` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt8)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getint8
+    pub fn get_int8( &self, byte_offset: u32 ) -> i8 {
+        js!( return @{self}.getInt8( @{byte_offset} ); ).try_into().unwrap()
+    }
+
+    /// Writes the `i8` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt8)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setint8
+    pub fn set_int8( &self, byte_offset: u32, value: i8 ) {
+        js! { @(no_return) @{self}.setInt8( @{byte_offset}, @{value} ); }
+    }
+
+    /// Reads the `u8` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint8)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getuint8
+    pub fn get_uint8( &self, byte_offset: u32 ) -> u8 {
+        js!( return @{self}.getUint8( @{byte_offset} ); ).try_into().unwrap()
+    }
+
+    /// Writes the `u8` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint8)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setuint8
+    pub fn set_uint8( &self, byte_offset: u32, value: u8 ) {
+        js! { @(no_return) @{self}.setUint8( @{byte_offset}, @{value} ); }
+    }
+
+    accessors! {
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getint16
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setint16
+        i16, get_int16, set_int16, getInt16, setInt16,
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt16",
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt16";
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getuint16
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setuint16
+        u16, get_uint16, set_uint16, getUint16, setUint16,
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint16",
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint16";
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getint32
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setint32
+        i32, get_int32, set_int32, getInt32, setInt32,
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getInt32",
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setInt32";
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getuint32
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setuint32
+        u32, get_uint32, set_uint32, getUint32, setUint32,
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getUint32",
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setUint32";
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getfloat64
+        // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setfloat64
+        f64, get_float64, set_float64, getFloat64, setFloat64,
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat64",
+            "https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat64";
+    }
+
+    /// Reads the `f32` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat32)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.getfloat32
+    pub fn get_float32( &self, byte_offset: u32, little_endian: bool ) -> f32 {
+        let value: f64 = js!(
+            return @{self}.getFloat32( @{byte_offset}, @{little_endian} );
+        ).try_into().unwrap();
+
+        value as f32
+    }
+
+    /// Writes the `f32` at the given byte offset.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat32)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-dataview.prototype.setfloat32
+    pub fn set_float32( &self, byte_offset: u32, value: f32, little_endian: bool ) {
+        js! { @(no_return)
+            @{self}.setFloat32( @{byte_offset}, @{value}, @{little_endian} );
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::typed_array::TypedArray;
+
+    #[test]
+    fn test_big_endian_u32() {
+        let buffer = ArrayBuffer::new( 8 ).unwrap();
+        let view = DataView::new( &buffer, 0, 8 );
+        view.set_uint32( 0, 0x01020304, false );
+
+        assert_eq!( view.get_uint32( 0, false ), 0x01020304 );
+        assert_eq!( view.get_uint32( 0, true ), 0x04030201 );
+
+        let bytes: Vec< u8 > = TypedArray::< u8 >::from( &buffer ).to_vec();
+        assert_eq!( &bytes[ 0..4 ], &[ 1, 2, 3, 4 ] );
+    }
+
+    #[test]
+    fn test_mixed_types() {
+        let buffer = ArrayBuffer::new( 16 ).unwrap();
+        let view = DataView::new( &buffer, 2, 14 );
+        assert_eq!( view.byte_offset(), 2 );
+        assert_eq!( view.byte_length(), 14 );
+        assert_eq!( view.buffer(), buffer );
+
+        view.set_int8( 0, -1 );
+        view.set_uint16( 1, 0xBEEF, true );
+        view.set_float64( 3, 1.5, false );
+
+        assert_eq!( view.get_int8( 0 ), -1 );
+        assert_eq!( view.get_uint8( 0 ), 0xFF );
+        assert_eq!( view.get_uint16( 1, true ), 0xBEEF );
+        assert_eq!( view.get_int16( 1, true ), 0xBEEFu16 as i16 );
+        assert_eq!( view.get_float64( 3, false ), 1.5 );
+    }
+}
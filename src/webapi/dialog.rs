@@ -0,0 +1,105 @@
+use webcore::try_from::TryInto;
+use webcore::promise_future::PromiseFuture;
+
+/// Shows a modal dialog with the given message and an OK and a Cancel button,
+/// and returns a future which resolves to `true` if the user clicked OK or to
+/// `false` if the user clicked Cancel.
+///
+/// Unlike [Window::confirm](../struct.Window.html#method.confirm) this doesn't block;
+/// the dialog is built from plain DOM elements and appended to the document's body.
+/// The dialog's root element has the `stdweb-dialog` class and its buttons have the
+/// `stdweb-dialog-ok` and `stdweb-dialog-cancel` classes, so they can be styled with CSS.
+pub fn confirm_async( message: &str ) -> PromiseFuture< bool > {
+    js!(
+        var message = @{message};
+        return new Promise( function( resolve ) {
+            var overlay = document.createElement( "div" );
+            overlay.className = "stdweb-dialog";
+            overlay.style.cssText = "position: fixed; top: 0; right: 0; bottom: 0; left: 0; z-index: 2147483647; " +
+                "display: flex; align-items: center; justify-content: center; background: rgba(0, 0, 0, 0.4);";
+
+            var dialog = document.createElement( "div" );
+            dialog.setAttribute( "role", "dialog" );
+            dialog.setAttribute( "aria-modal", "true" );
+            dialog.style.cssText = "background: white; color: black; padding: 1em; border-radius: 4px;";
+
+            var text = document.createElement( "p" );
+            text.textContent = message;
+
+            var ok = document.createElement( "button" );
+            ok.className = "stdweb-dialog-ok";
+            ok.textContent = "OK";
+
+            var cancel = document.createElement( "button" );
+            cancel.className = "stdweb-dialog-cancel";
+            cancel.textContent = "Cancel";
+
+            var close = function( result ) {
+                if( overlay.parentNode ) {
+                    overlay.parentNode.removeChild( overlay );
+                }
+                resolve( result );
+            };
+
+            ok.addEventListener( "click", function() { close( true ); } );
+            cancel.addEventListener( "click", function() { close( false ); } );
+
+            dialog.appendChild( text );
+            dialog.appendChild( ok );
+            dialog.appendChild( cancel );
+            overlay.appendChild( dialog );
+            ( document.body || document.documentElement ).appendChild( overlay );
+            ok.focus();
+        });
+    ).try_into().unwrap()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    #[cfg(rust_nightly)]
+    use async_test;
+    #[cfg(rust_nightly)]
+    use webcore::promise_future::spawn_local;
+    #[cfg(rust_nightly)]
+    use futures_util::FutureExt;
+    #[cfg(rust_nightly)]
+    use futures_util::future::join;
+    use webapi::document::document;
+    use webapi::node::INode;
+    use webapi::parent_node::IParentNode;
+
+    fn click( selector: &str ) {
+        let root = document().query_selector( ".stdweb-dialog" ).unwrap().unwrap();
+        let button = root.query_selector( selector ).unwrap().unwrap();
+        js!( @{button}.click(); );
+
+        assert!( document().query_selector( ".stdweb-dialog" ).unwrap().is_none() );
+        assert!( root.parent_node().is_none() );
+    }
+
+    #[test]
+    fn test_confirm_async_dialog() {
+        let _future = confirm_async( "Are you sure?" );
+
+        let root = document().query_selector( ".stdweb-dialog" ).unwrap().unwrap();
+        assert_eq!( js!( return @{&root}.textContent; ), "Are you sure?OKCancel" );
+        click( ".stdweb-dialog-ok" );
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_confirm_async_result< F: FnOnce() >( done: F ) {
+        let confirmed = confirm_async( "Confirm?" );
+        click( ".stdweb-dialog-ok" );
+
+        let cancelled = confirm_async( "Cancel?" );
+        click( ".stdweb-dialog-cancel" );
+
+        spawn_local( join( confirmed, cancelled ).map( move |(confirmed, cancelled)| {
+            assert_eq!( confirmed.unwrap(), true );
+            assert_eq!( cancelled.unwrap(), false );
+            done();
+        }));
+    }
+}
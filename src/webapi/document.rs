@@ -6,12 +6,14 @@ use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node, CloneKind};
 use webapi::element::Element;
 use webapi::html_element::HtmlElement;
+use webapi::html_collection::HtmlCollection;
 use webapi::document_fragment::DocumentFragment;
 use webapi::text_node::TextNode;
 use webapi::location::Location;
+use webapi::selection::Selection;
 use webapi::parent_node::IParentNode;
 use webapi::non_element_parent_node::INonElementParentNode;
-use webapi::dom_exception::{InvalidCharacterError, NamespaceError, NotSupportedError};
+use webapi::dom_exception::{HierarchyRequestError, InvalidCharacterError, NamespaceError, NotSupportedError};
 
 /// The `Document` interface represents any web page loaded in the browser and
 /// serves as an entry point into the web page's content, which is the DOM tree.
@@ -23,12 +25,49 @@ use webapi::dom_exception::{InvalidCharacterError, NamespaceError, NotSupportedE
 #[reference(subclass_of(EventTarget, Node))]
 pub struct Document( Reference );
 
+/// The namespace of HTML elements, for use with [Document::create_element_ns](../struct.Document.html#method.create_element_ns).
+pub const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// The namespace of SVG elements, for use with [Document::create_element_ns](../struct.Document.html#method.create_element_ns).
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// The namespace of MathML elements, for use with [Document::create_element_ns](../struct.Document.html#method.create_element_ns).
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// The visibility of a document, as returned by [Document::visibility_state](struct.Document.html#method.visibility_state).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+// https://w3c.github.io/page-visibility/#visibilitystate-attribute
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VisibilityState {
+    /// The page content may be at least partially visible.
+    Visible,
+    /// The page content is not visible to the user, e.g. it's in a background tab.
+    Hidden,
+    /// The page content is being prerendered and is not visible to the user.
+    Prerender
+}
+
 error_enum_boilerplate! {
+    /// An error returned from `Document::create_element_ns`.
     CreateElementNsError,
+
+    #[allow(missing_docs)]
     InvalidCharacterError,
+    #[allow(missing_docs)]
     NamespaceError
 }
 
+error_enum_boilerplate! {
+    /// An error returned from `Document::adopt_node`.
+    AdoptNodeError,
+
+    #[allow(missing_docs)]
+    NotSupportedError,
+    #[allow(missing_docs)]
+    HierarchyRequestError
+}
+
 impl IEventTarget for Document {}
 impl IParentNode for Document {}
 impl INode for Document {}
@@ -42,6 +81,48 @@ pub fn document() -> Document {
     unsafe { js!( return document; ).into_reference_unchecked() }.unwrap()
 }
 
+/// Sets the title of the global [document](fn.document.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/title)
+// https://html.spec.whatwg.org/#the-document-object:document.title
+pub fn set_document_title( title: &str ) {
+    document().set_title( title );
+}
+
+/// A cheaply cloneable handle which sets the title of the global
+/// [document](fn.document.html), optionally followed by a fixed suffix.
+///
+/// This is useful in single page applications which update the title
+/// on every route change, e.g. `"Inbox - My App"`, `"Settings - My App"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TitleBinding {
+    suffix: String
+}
+
+impl TitleBinding {
+    /// Creates a binding which sets titles as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a binding which appends the given suffix to every title it sets.
+    pub fn with_suffix( suffix: &str ) -> Self {
+        TitleBinding {
+            suffix: suffix.to_owned()
+        }
+    }
+
+    /// Sets the title of the document to `title` followed by this binding's suffix.
+    pub fn set( &self, title: &str ) {
+        set_document_title( &format!( "{}{}", title, self.suffix ) );
+    }
+
+    /// Returns the current title of the document.
+    pub fn get( &self ) -> String {
+        document().title()
+    }
+}
+
 impl Document {
     /// In an HTML document, the Document.createDocumentFragment() method creates a
     /// new empty DocumentFragment.
@@ -142,6 +223,55 @@ impl Document {
         js!( @(no_return) @{self}.title = @{title}; );
     }
 
+    /// Returns all of the cookies of the document which are visible to scripts,
+    /// as a string of `key=value` pairs separated by `; `.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie)
+    // https://html.spec.whatwg.org/#dom-document-cookie
+    pub fn cookie( &self ) -> String {
+        js!(
+            return @{self}.cookie;
+        ).try_into().unwrap()
+    }
+
+    /// Sets a single cookie, e.g. `set_cookie("key=value; path=/; max-age=3600")`.
+    ///
+    /// Despite its name this doesn't replace the whole cookie string returned
+    /// by [cookie](#method.cookie); it only adds or updates the one cookie which
+    /// is named in `value`, leaving the others untouched. To delete a cookie set
+    /// it again with `max-age=0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie)
+    // https://html.spec.whatwg.org/#dom-document-cookie
+    pub fn set_cookie( &self, value: &str ) {
+        js!( @(no_return) @{self}.cookie = @{value}; );
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all of the elements
+    /// in the document which have all of the given class names.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementsByClassName)
+    // https://dom.spec.whatwg.org/#ref-for-dom-document-getelementsbyclassname
+    pub fn get_elements_by_class_name( &self, class_names: &str ) -> HtmlCollection {
+        unsafe {
+            js!(
+                return @{self}.getElementsByClassName( @{class_names} );
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of elements with the given tag name.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementsByTagName)
+    // https://dom.spec.whatwg.org/#ref-for-dom-document-getelementsbytagname
+    pub fn get_elements_by_tag_name( &self, tag: &str ) -> HtmlCollection {
+        unsafe {
+            js!(
+                return @{self}.getElementsByTagName( @{tag} );
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Returns the Element that is the root element of the document (for example, the `<html>`
     /// element for HTML documents).
     ///
@@ -193,6 +323,19 @@ impl Document {
         ).unwrap()
     }
 
+    /// Transfers a node from another document into this one, removing it
+    /// from its parent (if any) and changing its `owner_document`.
+    ///
+    /// Unlike [import_node](#method.import_node) this moves the node instead of copying it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/adoptNode)
+    // https://dom.spec.whatwg.org/#ref-for-dom-document-adoptnode
+    pub fn adopt_node<N: INode>( &self, n: &N ) -> Result<Node, AdoptNodeError> {
+        js_try!(
+            return @{self}.adoptNode( @{n.as_ref()} );
+        ).unwrap()
+    }
+
     /// Check if the fullscreen API is enabled
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/fullscreenEnabled)
@@ -204,6 +347,16 @@ impl Document {
         }
     }
 
+    /// Returns the element which currently has focus, or `None` if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/activeElement)
+    // https://html.spec.whatwg.org/#dom-documentorshadowroot-activeelement
+    pub fn active_element( &self ) -> Option< Element > {
+        js!(
+            return @{self}.activeElement;
+        ).try_into().unwrap()
+    }
+
     /// Get the current fullscreen element, or None if there is nothing fullscreen
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DocumentOrShadowRoot/fullscreenElement)
@@ -214,6 +367,50 @@ impl Document {
             .downcast::<Element>()?)
     }
 
+    /// Returns a [Selection](struct.Selection.html) object representing the range of text selected
+    /// by the user or the current position of the caret.
+    ///
+    /// This is the same object as the one returned by [Window::get_selection](struct.Window.html#method.get_selection).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getSelection)
+    // https://w3c.github.io/selection-api/#dom-document-getselection
+    pub fn get_selection( &self ) -> Option< Selection > {
+        unsafe {
+            js!(
+                return @{self}.getSelection();
+            ).into_reference_unchecked()
+        }
+    }
+
+    /// Returns the visibility of the document.
+    ///
+    /// A [VisibilityChangeEvent](event/struct.VisibilityChangeEvent.html) is fired on the document when this changes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState)
+    // https://w3c.github.io/page-visibility/#dom-document-visibilitystate
+    pub fn visibility_state( &self ) -> VisibilityState {
+        let state: String = js!(
+            return @{self}.visibilityState;
+        ).try_into().unwrap();
+
+        match state.as_str() {
+            "visible" => VisibilityState::Visible,
+            "hidden" => VisibilityState::Hidden,
+            "prerender" => VisibilityState::Prerender,
+            _ => unreachable!( "Unexpected value of Document::visibilityState: {}", state )
+        }
+    }
+
+    /// Returns whether the document is hidden, i.e. not visible to the user.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/hidden)
+    // https://w3c.github.io/page-visibility/#dom-document-hidden
+    pub fn hidden( &self ) -> bool {
+        js!(
+            return @{self}.hidden;
+        ).try_into().unwrap()
+    }
+
     /// Request the page return from fullscreen mode to a normal state
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/exitFullscreen)
@@ -231,9 +428,12 @@ impl Document {
 #[cfg(all(test, feature = "web_test"))]
 mod web_tests {
     use super::*;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
     use webapi::node::{Node, INode, CloneKind};
     use webapi::html_elements::TemplateElement;
     use webapi::html_element::HtmlElement;
+    use webapi::element::IElement;
 
     #[test]
     fn test_create_element_invalid_character() {
@@ -259,6 +459,34 @@ mod web_tests {
         }
     }
 
+    #[test]
+    fn test_create_element_ns_svg() {
+        let svg = document().create_element_ns(SVG_NAMESPACE, "svg").unwrap();
+        assert_eq!(svg.namespace_uri(), Some(SVG_NAMESPACE.to_owned()));
+        assert!(js!( return @{&svg} instanceof SVGSVGElement; ) == true);
+
+        let math = document().create_element_ns(MATHML_NAMESPACE, "math").unwrap();
+        assert_eq!(math.namespace_uri(), Some(MATHML_NAMESPACE.to_owned()));
+
+        let div = document().create_element("div").unwrap();
+        assert_eq!(div.namespace_uri(), Some(HTML_NAMESPACE.to_owned()));
+    }
+
+    #[test]
+    fn test_cookie() {
+        let document = document();
+        document.set_cookie("stdweb_first=1; path=/");
+        document.set_cookie("stdweb_second=2; path=/");
+
+        let cookie = document.cookie();
+        assert!(cookie.split("; ").any(|pair| pair == "stdweb_first=1"));
+        assert!(cookie.split("; ").any(|pair| pair == "stdweb_second=2"));
+
+        document.set_cookie("stdweb_first=; path=/; max-age=0");
+        document.set_cookie("stdweb_second=; path=/; max-age=0");
+        assert!(!document.cookie().contains("stdweb_"));
+    }
+
     #[test]
     fn test_import_node() {
         let document = document();
@@ -276,4 +504,128 @@ mod web_tests {
         assert_eq!(span_element.node_name(), "SPAN");
         assert_eq!(js!( return @{span_element}.innerHTML; ), "aaabbbcccddd");
     }
+
+    #[test]
+    fn test_import_node_from_parsed_document() {
+        let document = document();
+        let parsed: Document = js!(
+            return new DOMParser().parseFromString( "<p id='test-import-node'>imported</p>", "text/html" );
+        ).try_into().unwrap();
+        let paragraph = parsed.get_element_by_id("test-import-node").unwrap();
+
+        let imported = document.import_node(&paragraph, CloneKind::Deep).unwrap();
+        assert_eq!(imported.owner_document().unwrap(), document);
+        assert_eq!(paragraph.owner_document().unwrap(), parsed);
+
+        let body = document.body().unwrap();
+        body.append_child(&imported);
+        let rendered = document.get_element_by_id("test-import-node").unwrap();
+        assert_eq!(rendered.text_content().unwrap(), "imported");
+        body.remove_child(&rendered).unwrap();
+    }
+
+    #[test]
+    fn test_adopt_node() {
+        let document = document();
+        let parsed: Document = js!(
+            return new DOMParser().parseFromString( "<p id='test-adopt-node'>adopted</p>", "text/html" );
+        ).try_into().unwrap();
+        let paragraph = parsed.get_element_by_id("test-adopt-node").unwrap();
+        assert_eq!(paragraph.owner_document().unwrap(), parsed);
+
+        let adopted = document.adopt_node(&paragraph).unwrap();
+        assert_eq!(adopted.as_ref(), paragraph.as_ref());
+        assert_eq!(paragraph.owner_document().unwrap(), document);
+        assert!(paragraph.parent_node().is_none());
+        assert!(parsed.get_element_by_id("test-adopt-node").is_none());
+    }
+
+    #[test]
+    fn test_adopt_node_not_supported() {
+        let document = document();
+        match document.adopt_node(&document) {
+            Err(AdoptNodeError::NotSupportedError(_)) => (),
+            v => panic!("expected NotSupportedError, got {:?}", v),
+        }
+    }
+
+    #[test]
+    fn test_get_elements_by_class_name_is_live() {
+        let document = document();
+        let body = document.body().unwrap();
+        let collection = document.get_elements_by_class_name("test-get-elements-by-class-name");
+        assert_eq!(collection.len(), 0);
+
+        let element = document.create_element("div").unwrap();
+        element.class_list().add("test-get-elements-by-class-name").unwrap();
+        body.append_child(&element);
+        assert_eq!(collection.len(), 1);
+
+        body.remove_child(&element).unwrap();
+        assert_eq!(collection.len(), 0);
+    }
+
+    #[test]
+    fn test_get_elements_by_tag_name_is_live() {
+        let document = document();
+        let body = document.body().unwrap();
+        let collection = document.get_elements_by_tag_name("article");
+        let initial = collection.len();
+
+        let element = document.create_element("article").unwrap();
+        body.append_child(&element);
+        assert_eq!(collection.len(), initial + 1);
+
+        body.remove_child(&element).unwrap();
+        assert_eq!(collection.len(), initial);
+    }
+
+    #[test]
+    fn test_visibility_state() {
+        let document = document();
+        let expected = if document.hidden() { VisibilityState::Hidden } else { VisibilityState::Visible };
+        assert_eq!(document.visibility_state(), expected);
+    }
+
+    #[test]
+    fn test_set_document_title() {
+        let original = document().title();
+
+        set_document_title("stdweb");
+        assert_eq!(document().title(), "stdweb");
+
+        let binding = TitleBinding::with_suffix(" - stdweb");
+        binding.clone().set("Inbox");
+        assert_eq!(document().title(), "Inbox - stdweb");
+        assert_eq!(binding.get(), "Inbox - stdweb");
+
+        TitleBinding::new().set("Settings");
+        assert_eq!(document().title(), "Settings");
+
+        set_document_title(&original);
+    }
+
+    #[test]
+    fn test_fullscreen_element() {
+        // Fullscreen can only be entered from a user gesture, so nothing is fullscreen in tests.
+        assert_eq!(document().fullscreen_element(), None);
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_request_fullscreen_outside_of_user_gesture<F: FnOnce()>(done: F) {
+        use webcore::promise_future::spawn_local;
+        use futures_util::FutureExt;
+
+        let element = document().create_element("div").unwrap();
+        document().body().unwrap().append_child(&element);
+
+        spawn_local(element.request_fullscreen().to_future().map(move |result| {
+            // The type of the error guarantees that it's a `TypeError`.
+            assert!(result.is_err());
+            assert_eq!(document().fullscreen_element(), None);
+            document().body().unwrap().remove_child(&element).unwrap();
+            done();
+        }));
+    }
 }
@@ -165,6 +165,29 @@ impl IDomException for NamespaceError {}
 
 error_boilerplate! { NamespaceError, dom_exception = "NamespaceError" }
 
+/// Used to indicate that a mutation operation in a transaction failed because a constraint
+/// was not satisfied, e.g. a duplicate key was added to a unique index.
+// https://heycam.github.io/webidl/#constrainterror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct ConstraintError( Reference );
+
+impl IError for ConstraintError {}
+impl IDomException for ConstraintError {}
+
+error_boilerplate! { ConstraintError, dom_exception = "ConstraintError" }
+
+/// Used to indicate that the operation would exceed the available storage quota.
+// https://heycam.github.io/webidl/#quotaexceedederror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error, DomException))]
+pub struct QuotaExceededError( Reference );
+
+impl IError for QuotaExceededError {}
+impl IDomException for QuotaExceededError {}
+
+error_boilerplate! { QuotaExceededError, dom_exception = "QuotaExceededError" }
+
 #[cfg(all(test, feature = "web_test"))]
 mod test {
     use super::*;
@@ -189,4 +212,15 @@ mod test {
         let err: Result<SyntaxError, _> = err.try_into();
         assert!(err.is_err());
     }
+
+    #[test]
+    fn test_storage_errors() {
+        let err: DomException = new_dom_exception("foo", "ConstraintError");
+        let err: ConstraintError = err.try_into().expect("Expected ConstraintError");
+        assert_eq!(err.name(), "ConstraintError");
+
+        let err: DomException = new_dom_exception("foo", "QuotaExceededError");
+        let err: Result<ConstraintError, _> = err.try_into();
+        assert!(err.is_err());
+    }
 }
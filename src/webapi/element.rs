@@ -6,6 +6,9 @@ use webapi::dom_exception::{InvalidCharacterError, InvalidPointerId, NoModificat
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::token_list::TokenList;
+use webapi::html_collection::HtmlCollection;
+use webapi::html_element::Rect;
+use webapi::intersection_observer::{IntersectionObserver, IntersectionObserverHandle};
 use webapi::parent_node::IParentNode;
 use webapi::child_node::IChildNode;
 use webapi::slotable::ISlotable;
@@ -82,6 +85,32 @@ pub trait IElement: INode + IParentNode + IChildNode + ISlotable {
         ).unwrap()
     }
 
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all of the descendant
+    /// elements which have all of the given class names.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getElementsByClassName)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-getelementsbyclassname
+    fn get_elements_by_class_name( &self, class_names: &str ) -> HtmlCollection {
+        unsafe {
+            js!(
+                return @{self.as_ref()}.getElementsByClassName( @{class_names} );
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns a live [HtmlCollection](struct.HtmlCollection.html) of all of the descendant
+    /// elements with the given tag name.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getElementsByTagName)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-getelementsbytagname
+    fn get_elements_by_tag_name( &self, tag: &str ) -> HtmlCollection {
+        unsafe {
+            js!(
+                return @{self.as_ref()}.getElementsByTagName( @{tag} );
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Gets the the number of pixels that an element's content is scrolled vertically.
     ///
     /// [(Javascript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollTop)
@@ -166,6 +195,39 @@ pub trait IElement: INode + IParentNode + IChildNode + ISlotable {
         ).unwrap()
     }
 
+    /// Returns `true` if the element would be selected by the given selectors.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/matches)
+    // https://dom.spec.whatwg.org/#ref-for-dom-element-matches
+    fn matches( &self, selectors: &str ) -> Result< bool, SyntaxError > {
+        js_try!(
+            var element = @{self.as_ref()};
+            var matches = element.matches || element.msMatchesSelector || element.webkitMatchesSelector;
+            return matches.call( element, @{selectors} );
+        ).unwrap()
+    }
+
+    /// Returns the size of an element and its position relative to the viewport.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect)
+    // https://drafts.csswg.org/cssom-view/#ref-for-dom-element-getboundingclientrect
+    fn get_bounding_client_rect( &self ) -> Rect {
+        js! (
+            return @{self.as_ref()}.getBoundingClientRect();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the bounding rectangles of each CSS border box of the element,
+    /// e.g. one per line box for an inline element which wraps over multiple lines.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getClientRects)
+    // https://drafts.csswg.org/cssom-view/#dom-element-getclientrects
+    fn get_client_rects( &self ) -> Vec< Rect > {
+        js!(
+            return Array.prototype.slice.call( @{self.as_ref()}.getClientRects() );
+        ).try_into().unwrap()
+    }
+
     /// Designates a specific element as the capture target of future pointer events.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/setPointerCapture)
@@ -269,6 +331,47 @@ pub trait IElement: INode + IParentNode + IChildNode + ISlotable {
         }
     }
 
+    /// Calls the callback with `true` when the element enters the viewport and
+    /// with `false` when it leaves it.
+    ///
+    /// The callback is also called once shortly after this is called with the
+    /// initial visibility. The element stops being observed when the returned
+    /// handle is dropped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API)
+    fn on_visible< F >( &self, mut callback: F ) -> IntersectionObserverHandle
+        where F: FnMut( bool ) + 'static, Self: Sized
+    {
+        let observer = IntersectionObserver::new( move |entries, _| {
+            if let Some( entry ) = entries.last() {
+                callback( entry.is_intersecting() );
+            }
+        });
+        observer.observe( self );
+        observer
+    }
+
+    /// Requests that the pointer be locked to this element, hiding the cursor
+    /// and delivering raw [movement](event/trait.IMouseEvent.html#method.movement_x) deltas
+    /// in mouse events.
+    ///
+    /// The outcome is reported asynchronously through a `PointerLockChangeEvent` or
+    /// a `PointerLockErrorEvent` dispatched on the document. The request is usually
+    /// only granted during a user interaction.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/requestPointerLock)
+    // https://w3c.github.io/pointerlock/#dom-element-requestpointerlock
+    fn request_pointer_lock( &self ) {
+        js! { @(no_return)
+            var promise = @{self.as_ref()}.requestPointerLock();
+            // Newer browsers also return a promise; failures are already
+            // reported through the `pointerlockerror` event.
+            if( promise ) {
+                promise.catch( function() {} );
+            }
+        }
+    }
+
     /// Request this element and its children be made fullscreen
     ///
     /// Note: this may only be called during a user interaction.
@@ -282,6 +385,23 @@ pub trait IElement: INode + IParentNode + IChildNode + ISlotable {
 
         TypedPromise::new( promise )
     }
+
+    /// Request this element and its children be made fullscreen, using the given options.
+    ///
+    /// Note: this may only be called during a user interaction.
+    /// Not all elements may be full-screened, see JS docs for details.
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/requestFullscreen)
+    // https://fullscreen.spec.whatwg.org/#ref-for-dom-element-requestfullscreen
+    #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
+    fn request_fullscreen_with_options( &self, options: FullscreenOptions ) -> TypedPromise<(), TypeError> {
+        let promise: Promise = js!(
+            return @{self.as_ref()}.requestFullscreen( {
+                navigationUI: @{options.navigation_ui.as_str()}
+            } );
+        ).try_into().unwrap();
+
+        TypedPromise::new( promise )
+    }
 }
 
 
@@ -303,6 +423,10 @@ impl< T: IElement > IParentNode for T {}
 impl< T: IElement > IChildNode for T {}
 impl< T: IElement > ISlotable for T {}
 
+/// The position at which [IElement::insert_adjacent_html](trait.IElement.html#method.insert_adjacent_html)
+/// inserts the parsed nodes, relative to the element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/insertAdjacentHTML#Parameters)
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum InsertPosition {
     /// Insert into the parent directly before the reference element.
@@ -315,10 +439,14 @@ pub enum InsertPosition {
     AfterEnd,
 }
 
-/// Errors thrown by `Element::insert_adjacent_html`.
 error_enum_boilerplate! {
+    /// Errors thrown by `Element::insert_adjacent_html`.
     InsertAdjacentError,
-    NoModificationAllowedError, SyntaxError
+
+    #[allow(missing_docs)]
+    NoModificationAllowedError,
+    #[allow(missing_docs)]
+    SyntaxError
 }
 
 impl InsertPosition {
@@ -332,9 +460,52 @@ impl InsertPosition {
     }
 }
 
+/// Whether the browser's navigation UI should be shown while an element is fullscreen.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FullscreenOptions/navigationUI)
+// https://fullscreen.spec.whatwg.org/#enumdef-fullscreennavigationui
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FullscreenNavigationUi {
+    /// The browser decides whether to show the navigation UI. (default)
+    Auto,
+    /// The navigation UI is shown, which may leave less space for the element.
+    Show,
+    /// The navigation UI is hidden to give the element as much space as possible.
+    Hide,
+}
+
+impl Default for FullscreenNavigationUi {
+    fn default() -> Self {
+        FullscreenNavigationUi::Auto
+    }
+}
+
+impl FullscreenNavigationUi {
+    #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
+    fn as_str(&self) -> &str {
+        match *self {
+            FullscreenNavigationUi::Auto => "auto",
+            FullscreenNavigationUi::Show => "show",
+            FullscreenNavigationUi::Hide => "hide",
+        }
+    }
+}
+
+/// Options for [IElement::request_fullscreen_with_options](trait.IElement.html#method.request_fullscreen_with_options).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FullscreenOptions)
+// https://fullscreen.spec.whatwg.org/#dictdef-fullscreenoptions
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct FullscreenOptions {
+    /// Whether the browser's navigation UI should be shown while the element is fullscreen.
+    pub navigation_ui: FullscreenNavigationUi,
+}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
+    #[cfg(rust_nightly)]
+    use async_test;
     use webapi::document::document;
     use webapi::shadow_root::ShadowRootMode;
 
@@ -377,6 +548,119 @@ mod tests {
         assert!(child.closest("invalid syntax +#8$()@!(#").is_err());
     }
 
+    #[test]
+    fn test_matches() {
+        let parent = div();
+        let child = h1();
+        child.class_list().add("foo").unwrap();
+        parent.append_child(&child);
+
+        assert!(child.matches("div > h1.foo").unwrap());
+        assert!(!child.matches("p").unwrap());
+        assert!(child.matches("invalid syntax +#8$()@!(#").is_err());
+    }
+
+    #[test]
+    fn test_get_elements_by_class_name() {
+        let parent = div();
+        let collection = parent.get_elements_by_class_name("foo");
+        assert_eq!(collection.len(), 0);
+
+        let child = h1();
+        child.class_list().add("foo").unwrap();
+        parent.append_child(&child);
+        assert_eq!(collection.len(), 1);
+        assert_eq!(collection.item(0).unwrap().as_ref(), child.as_ref());
+    }
+
+    #[test]
+    fn test_get_elements_by_tag_name() {
+        let parent = div();
+        let collection = parent.get_elements_by_tag_name("h1");
+        assert_eq!(collection.len(), 0);
+
+        parent.append_child(&h1());
+        parent.append_child(&div());
+        assert_eq!(collection.len(), 1);
+    }
+
+    #[test]
+    fn test_set_attribute() {
+        use webapi::error::IError;
+
+        let element = div();
+        element.set_attribute("data-foo", "bar").unwrap();
+        assert_eq!(element.get_attribute("data-foo"), Some("bar".to_owned()));
+
+        let error = element.set_attribute("foo bar", "baz").unwrap_err();
+        assert_eq!(error.name(), "InvalidCharacterError");
+        assert!(!element.has_attribute("foo bar"));
+    }
+
+    #[test]
+    fn test_get_client_rects() {
+        let container = div();
+        container.set_attribute("style", "width: 50px").unwrap();
+        let span: Element = document().create_element("span").unwrap();
+        span.append_child(&document().create_text_node("several words which have to wrap over multiple lines"));
+        container.append_child(&span);
+        document().body().unwrap().append_child(&container);
+
+        let rects = span.get_client_rects();
+        assert!(rects.len() > 1);
+        assert!(rects.iter().all(|rect| rect.get_width() > 0.0));
+
+        document().body().unwrap().remove_child(&container).unwrap();
+        assert!(span.get_client_rects().is_empty());
+    }
+
+    #[test]
+    fn test_get_bounding_client_rect() {
+        let svg: Element = document().create_element_ns("http://www.w3.org/2000/svg", "svg").unwrap();
+        svg.set_attribute("width", "30").unwrap();
+        svg.set_attribute("height", "20").unwrap();
+        document().body().unwrap().append_child(&svg);
+
+        let rect = svg.get_bounding_client_rect();
+        assert_eq!(rect.get_width(), 30.0);
+        assert_eq!(rect.get_height(), 20.0);
+        assert_eq!(rect.get_right() - rect.get_left(), 30.0);
+
+        document().body().unwrap().remove_child(&svg).unwrap();
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_on_visible<F: FnOnce()>(done: F) {
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use webapi::global::set_timeout;
+
+        let element = div();
+        element.set_attribute("style", "position: fixed; top: 0; left: 0; width: 10px; height: 10px;").unwrap();
+        document().body().unwrap().append_child(&element);
+
+        let handle = Rc::new(RefCell::new(None));
+        let mut done = Some(done);
+        *handle.borrow_mut() = Some(element.on_visible({
+            let handle = handle.clone();
+            let element = element.clone();
+            move |visible| {
+                assert!(visible);
+                if let Some(done) = done.take() {
+                    let handle = handle.clone();
+                    let element = element.clone();
+                    // The handle can't be dropped from within its own callback.
+                    set_timeout(move || {
+                        handle.borrow_mut().take();
+                        document().body().unwrap().remove_child(&element).unwrap();
+                        done();
+                    }, 0);
+                }
+            }
+        }));
+    }
+
     #[test]
     fn insert_adjacent_html() {
         let root = document().create_element("div").unwrap();
@@ -426,4 +710,55 @@ mod tests {
         assert_eq!(shadow_root.mode(), ShadowRootMode::Closed);
         assert!(element.shadow_root().is_none());
     }
+
+    #[cfg(feature = "experimental_features_which_may_break_on_minor_version_bumps")]
+    #[test]
+    fn test_fullscreen_navigation_ui() {
+        assert_eq!(FullscreenOptions::default().navigation_ui, FullscreenNavigationUi::Auto);
+        assert_eq!(FullscreenNavigationUi::Auto.as_str(), "auto");
+        assert_eq!(FullscreenNavigationUi::Show.as_str(), "show");
+        assert_eq!(FullscreenNavigationUi::Hide.as_str(), "hide");
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_request_fullscreen_with_options<F: FnOnce()>(done: F) {
+        use webcore::promise_future::spawn_local;
+        use futures_util::FutureExt;
+        use webcore::value::Value;
+
+        let element = div();
+        let captured: Value = js!(
+            var element = @{&element};
+            var captured = {};
+            var request_fullscreen = element.requestFullscreen;
+            element.requestFullscreen = function(options) {
+                captured.options = options;
+                return request_fullscreen.call(element, options);
+            };
+            return captured;
+        );
+
+        // Without a user gesture the request is rejected.
+        let promise = element.request_fullscreen_with_options(FullscreenOptions { navigation_ui: FullscreenNavigationUi::Hide });
+        assert_eq!(js!( return @{&captured}.options.navigationUI; ), "hide");
+
+        spawn_local(promise.to_future().map(move |result| {
+            assert!(result.is_err());
+            done();
+        }));
+    }
+
+    #[test]
+    fn test_request_pointer_lock() {
+        use webapi::document::document;
+
+        // Without a user gesture the request fails asynchronously, but it must not throw.
+        let element = div();
+        document().body().unwrap().append_child(&element);
+        element.request_pointer_lock();
+        assert_eq!(document().pointer_lock_element(), None);
+        document().exit_pointer_lock();
+        document().body().unwrap().remove_child(&element).unwrap();
+    }
 }
@@ -65,6 +65,17 @@ impl IError for TypeError {}
 
 error_boilerplate! { TypeError }
 
+/// Used to indicate that a value is not in the set or range of allowed values.
+// https://tc39.github.io/ecma262/#sec-native-error-types-used-in-this-standard-rangeerror
+#[derive(Clone, Debug, ReferenceType)]
+#[reference(subclass_of(Error))]
+#[reference(instance_of = "RangeError")]
+pub struct RangeError( Reference );
+
+impl IError for RangeError {}
+
+error_boilerplate! { RangeError }
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -90,4 +101,9 @@ mod test {
     fn test_type_error() {
         let _: TypeError = js!( return new TypeError( "Big bad wolf" ); ).try_into().unwrap();
     }
+
+    #[test]
+    fn test_range_error() {
+        let _: RangeError = js!( return new RangeError( "Out of range" ); ).try_into().unwrap();
+    }
 }
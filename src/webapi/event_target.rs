@@ -5,7 +5,7 @@ use webcore::try_from::TryInto;
 use webcore::reference_type::ReferenceType;
 use webcore::mutfn::Mut;
 use webapi::event::{ConcreteEvent, IEvent};
-use private::TODO;
+use webapi::dom_exception::InvalidStateError;
 
 /// A handle to a particular event listener.
 pub struct EventListenerHandle {
@@ -67,11 +67,16 @@ pub trait IEventTarget: ReferenceType {
     /// Dispatches an `Event` at this `EventTarget`, invoking the affected event listeners in the
     /// appropriate order.
     ///
+    /// Returns `false` if the event is cancelable and one of its listeners called
+    /// `prevent_default`, or `true` otherwise. Returns an `InvalidStateError` if the
+    /// event is already being dispatched or wasn't initialized.
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/dispatchEvent)
-    fn dispatch_event< T: IEvent >( &self, event: &T ) -> Result< bool, TODO > {
-        Ok( js! (
+    // https://dom.spec.whatwg.org/#dom-eventtarget-dispatchevent
+    fn dispatch_event< T: IEvent >( &self, event: &T ) -> Result< bool, InvalidStateError > {
+        js_try! (
             return @{self.as_ref()}.dispatchEvent( @{event.as_ref()} );
-        ).try_into().unwrap() )
+        ).unwrap()
     }
 }
 
@@ -0,0 +1,103 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event};
+use webapi::events::drag::DataTransfer;
+
+/// The `IClipboardEvent` interface represents events providing information
+/// related to modification of the clipboard, that is cut, copy, and paste events.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardEvent)
+// https://w3c.github.io/clipboard-apis/#clipboard-event-interfaces
+pub trait IClipboardEvent: IEvent {
+    /// Returns the data affected by the clipboard operation.
+    ///
+    /// Handlers of the copy and cut events can modify it to change what is put on the clipboard,
+    /// while handlers of the paste event can read it to find out what is being pasted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardEvent/clipboardData)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboardevent-clipboarddata
+    #[inline]
+    fn clipboard_data( &self ) -> Option< DataTransfer > {
+        js!(
+            return @{self.as_ref()}.clipboardData;
+        ).try_into().unwrap()
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IClipboardEvent](trait.IClipboardEvent.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardEvent)
+// https://w3c.github.io/clipboard-apis/#clipboard-event-interfaces
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ClipboardEvent")]
+#[reference(subclass_of(Event))]
+pub struct ClipboardRelatedEvent( Reference );
+
+impl IEvent for ClipboardRelatedEvent {}
+impl IClipboardEvent for ClipboardRelatedEvent {}
+
+/// The `CopyEvent` is fired when the user initiates a copy action through the browser's user interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/copy)
+// https://w3c.github.io/clipboard-apis/#clipboard-event-copy
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ClipboardEvent")]
+#[reference(event = "copy")]
+#[reference(subclass_of(Event, ClipboardRelatedEvent))]
+pub struct CopyEvent( Reference );
+
+impl IEvent for CopyEvent {}
+impl IClipboardEvent for CopyEvent {}
+
+/// The `CutEvent` is fired when the user initiates a cut action through the browser's user interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/cut)
+// https://w3c.github.io/clipboard-apis/#clipboard-event-cut
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ClipboardEvent")]
+#[reference(event = "cut")]
+#[reference(subclass_of(Event, ClipboardRelatedEvent))]
+pub struct CutEvent( Reference );
+
+impl IEvent for CutEvent {}
+impl IClipboardEvent for CutEvent {}
+
+/// The `PasteEvent` is fired when the user initiates a paste action through the browser's user interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/paste)
+// https://w3c.github.io/clipboard-apis/#clipboard-event-paste
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ClipboardEvent")]
+#[reference(event = "paste")]
+#[reference(subclass_of(Event, ClipboardRelatedEvent))]
+pub struct PasteEvent( Reference );
+
+impl IEvent for PasteEvent {}
+impl IClipboardEvent for PasteEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_copy_event() {
+        let event: CopyEvent = js!(
+            return new ClipboardEvent( @{CopyEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), CopyEvent::EVENT_TYPE );
+        assert!( event.clipboard_data().is_none() );
+    }
+
+    #[test]
+    fn test_paste_event() {
+        let event: PasteEvent = js!(
+            var data = new DataTransfer();
+            data.setData( "text/plain", "pasted" );
+            return new ClipboardEvent( @{PasteEvent::EVENT_TYPE}, { clipboardData: data } );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), PasteEvent::EVENT_TYPE );
+        assert_eq!( event.clipboard_data().unwrap().get_data( "text/plain" ), "pasted" );
+    }
+}
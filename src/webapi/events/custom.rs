@@ -0,0 +1,83 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webcore::serialization::JsSerialize;
+use webapi::event::{IEvent, Event};
+
+/// The `CustomEvent` is an event with an arbitrary type which carries
+/// application-specific data in its [detail](#method.detail).
+///
+/// Since its type is only known at runtime, it can be fired with
+/// [IEventTarget::dispatch_event](../trait.IEventTarget.html#method.dispatch_event),
+/// but not listened to with `add_event_listener`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent)
+// https://dom.spec.whatwg.org/#interface-customevent
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "CustomEvent")]
+#[reference(subclass_of(Event))]
+pub struct CustomEvent( Reference );
+
+impl IEvent for CustomEvent {}
+
+impl CustomEvent {
+    /// Creates a new `CustomEvent` of the given type carrying `detail`.
+    ///
+    /// The event doesn't bubble and isn't cancelable.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/CustomEvent)
+    // https://dom.spec.whatwg.org/#dom-customevent-customevent
+    pub fn new< T: JsSerialize >( event_type: &str, detail: T ) -> Self {
+        js!(
+            return new CustomEvent( @{event_type}, { detail: @{detail} } );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the data passed when the event was created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent/detail)
+    // https://dom.spec.whatwg.org/#dom-customevent-detail
+    pub fn detail( &self ) -> Value {
+        js!(
+            return @{self}.detail;
+        )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::event_target::IEventTarget;
+
+    #[test]
+    fn test_custom_event() {
+        let event = CustomEvent::new( "stdweb-custom", 42 );
+        assert_eq!( event.event_type(), "stdweb-custom" );
+        assert_eq!( event.detail(), 42 );
+        assert!( !event.bubbles() );
+    }
+
+    #[test]
+    fn test_dispatch_custom_event() {
+        let element = document().create_element( "div" ).unwrap();
+        let details = js!(
+            var details = [];
+            @{&element}.addEventListener( "stdweb-custom", function( event ) {
+                details.push( event.detail );
+            });
+            return details;
+        );
+
+        let event = CustomEvent::new( "stdweb-custom", "payload" );
+        assert_eq!( element.dispatch_event( &event ).unwrap(), true );
+        assert_eq!( js!( return @{&details}.length; ), 1 );
+        assert_eq!( js!( return @{&details}[ 0 ]; ), "payload" );
+    }
+
+    #[test]
+    fn test_dispatch_uninitialized_event() {
+        let element = document().create_element( "div" ).unwrap();
+        let event: Event = js!( return document.createEvent( "Event" ); ).try_into().unwrap();
+        assert!( element.dispatch_event( &event ).is_err() );
+    }
+}
@@ -63,6 +63,10 @@ impl IUiEvent for ResourceAbortEvent {}
 /// The `ResourceErrorEvent` is fired when an error occurred; the exact circumstances vary,
 /// since this event is used from a variety of APIs.
 ///
+/// Uncaught script errors are reported on the window as an [ErrorEvent](struct.ErrorEvent.html)
+/// instead, which uses the same event type; listening for a `ResourceErrorEvent` on the
+/// window makes the listener panic when a script error is reported.
+///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/error)
 // https://w3c.github.io/uievents/#event-type-error
 #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
@@ -137,6 +141,43 @@ pub struct SelectionChangeEvent( Reference );
 
 impl IEvent for SelectionChangeEvent {}
 
+/// The online event is fired on the window when the browser has gained access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/online)
+// https://html.spec.whatwg.org/#event-online
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "online")]
+#[reference(subclass_of(Event))]
+pub struct OnlineEvent( Reference );
+
+impl IEvent for OnlineEvent {}
+
+/// The offline event is fired on the window when the browser has lost access to the network.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/offline)
+// https://html.spec.whatwg.org/#event-offline
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "offline")]
+#[reference(subclass_of(Event))]
+pub struct OfflineEvent( Reference );
+
+impl IEvent for OfflineEvent {}
+
+/// The visibilitychange event is fired on the document when the content of its tab
+/// has become visible or has been hidden.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/visibilitychange)
+// https://w3c.github.io/page-visibility/#reacting-to-visibilitychange-changes
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "visibilitychange")]
+#[reference(subclass_of(Event))]
+pub struct VisibilityChangeEvent( Reference );
+
+impl IEvent for VisibilityChangeEvent {}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -206,4 +247,25 @@ mod tests {
         ).try_into().unwrap();
         assert_eq!( event.event_type(), SelectionChangeEvent::EVENT_TYPE);
     }
+
+    #[test]
+    fn test_online_offline_events() {
+        let event: OnlineEvent = js!(
+            return new Event( @{OnlineEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), OnlineEvent::EVENT_TYPE );
+
+        let event: OfflineEvent = js!(
+            return new Event( @{OfflineEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), OfflineEvent::EVENT_TYPE );
+    }
+
+    #[test]
+    fn test_visibility_change_event() {
+        let event: VisibilityChangeEvent = js!(
+            return new Event( @{VisibilityChangeEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), VisibilityChangeEvent::EVENT_TYPE );
+    }
 }
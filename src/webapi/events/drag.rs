@@ -479,7 +479,7 @@ impl DataTransferItemList {
         }
     }
 
-    /// Returns an iterator over the list
+    /// Returns an iterator over the list.
     pub fn iter( &self ) -> DataTransferItemIter {
         DataTransferItemIter {
             list: self.clone(),
@@ -526,6 +526,9 @@ impl Iterator for DataTransferItemIter {
     }
 }
 
+/// An iterator over the items of a [DataTransferItemList](struct.DataTransferItemList.html).
+///
+/// This is created by the [DataTransferItemList::iter](struct.DataTransferItemList.html#method.iter) method.
 #[derive(Debug)]
 pub struct DataTransferItemIter {
     list: DataTransferItemList,
@@ -716,4 +719,33 @@ mod tests {
         event.data_transfer().unwrap().items().clear();
         assert_eq!(event.data_transfer().unwrap().items().len(), 0);
     }
+
+    #[test]
+    fn test_drop_effect_round_trip() {
+        // Unlike `effectAllowed`, `dropEffect` can be changed outside of a `dragstart` handler.
+        let data_transfer: DataTransfer = js!( return new DataTransfer(); ).try_into().unwrap();
+        for &effect in &[DropEffect::Move, DropEffect::Copy, DropEffect::Link, DropEffect::None] {
+            data_transfer.set_drop_effect(effect);
+            assert_eq!(data_transfer.drop_effect(), effect);
+        }
+    }
+
+    #[test]
+    fn test_data_transfer_item_iter() {
+        let data_transfer: DataTransfer = js!( return new DataTransfer(); ).try_into().unwrap();
+        let items = data_transfer.items();
+        items.add_string("first", "text/plain").unwrap();
+        let file = js!( return new File(["content"], "name", { type: "text/html" }); ).try_into().unwrap();
+        items.add_file(&file).unwrap();
+
+        let collected: Vec<DataTransferItem> = items.iter().collect();
+        assert_eq!(collected.len(), items.len() as usize);
+        assert_eq!(collected[0].kind(), DataTransferItemKind::String);
+        assert_eq!(collected[0].ty(), "text/plain");
+        assert_eq!(collected[1].kind(), DataTransferItemKind::File);
+        assert_eq!(collected[1].ty(), "text/html");
+
+        let kinds: Vec<DataTransferItemKind> = (&items).into_iter().map(|item| item.kind()).collect();
+        assert_eq!(kinds, vec![DataTransferItemKind::String, DataTransferItemKind::File]);
+    }
 }
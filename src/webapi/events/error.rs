@@ -0,0 +1,233 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::event::{IEvent, Event};
+
+/// The `ErrorEvent` is fired on the window when a script throws an uncaught exception.
+///
+/// It shares the `error` event type with [ResourceErrorEvent](struct.ResourceErrorEvent.html),
+/// which is fired on an element when loading a resource like an image or a script fails.
+/// Since listeners are registered by the event type alone, a `ResourceErrorEvent` listener
+/// on the window also receives script errors, and an `ErrorEvent` listener on an element
+/// also receives resource errors; in both cases the listener panics because the event
+/// can't be converted. Only listen for `ErrorEvent`s on the window.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent)
+// https://html.spec.whatwg.org/#the-errorevent-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ErrorEvent")]
+#[reference(event = "error")]
+#[reference(subclass_of(Event))]
+pub struct ErrorEvent( Reference );
+
+impl IEvent for ErrorEvent {}
+
+impl ErrorEvent {
+    /// Returns a human-readable description of the error.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/message)
+    // https://html.spec.whatwg.org/#dom-errorevent-message
+    pub fn message( &self ) -> String {
+        js!(
+            return @{self}.message;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the URL of the script in which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/filename)
+    // https://html.spec.whatwg.org/#dom-errorevent-filename
+    pub fn filename( &self ) -> String {
+        js!(
+            return @{self}.filename;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the line number at which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/lineno)
+    // https://html.spec.whatwg.org/#dom-errorevent-lineno
+    pub fn lineno( &self ) -> u32 {
+        js!(
+            return @{self}.lineno;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the column number at which the error occurred.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/colno)
+    // https://html.spec.whatwg.org/#dom-errorevent-colno
+    pub fn colno( &self ) -> u32 {
+        js!(
+            return @{self}.colno;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value which was thrown, usually an [Error](../error/struct.Error.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent/error)
+    // https://html.spec.whatwg.org/#dom-errorevent-error
+    pub fn error( &self ) -> Value {
+        js!(
+            return @{self}.error;
+        )
+    }
+}
+
+/// The `IPromiseRejectionEvent` interface represents events which are fired
+/// on the window when a promise is rejected.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent)
+// https://html.spec.whatwg.org/#the-promiserejectionevent-interface
+pub trait IPromiseRejectionEvent: IEvent {
+    /// Returns the value with which the promise was rejected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent/reason)
+    // https://html.spec.whatwg.org/#dom-promiserejectionevent-reason
+    #[inline]
+    fn reason( &self ) -> Value {
+        js!(
+            return @{self.as_ref()}.reason;
+        )
+    }
+
+    /// Returns the promise which was rejected.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent/promise)
+    // https://html.spec.whatwg.org/#dom-promiserejectionevent-promise
+    #[inline]
+    fn promise( &self ) -> Value {
+        js!(
+            return @{self.as_ref()}.promise;
+        )
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IPromiseRejectionEvent](trait.IPromiseRejectionEvent.html)
+/// interface.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent)
+// https://html.spec.whatwg.org/#the-promiserejectionevent-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PromiseRejectionEvent")]
+#[reference(subclass_of(Event))]
+pub struct PromiseRejectionEvent( Reference );
+
+impl IEvent for PromiseRejectionEvent {}
+impl IPromiseRejectionEvent for PromiseRejectionEvent {}
+
+/// The `UnhandledRejectionEvent` is fired on the window when a promise is
+/// rejected and has no rejection handler.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/unhandledrejection_event)
+// https://html.spec.whatwg.org/#event-unhandledrejection
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PromiseRejectionEvent")]
+#[reference(event = "unhandledrejection")]
+#[reference(subclass_of(Event, PromiseRejectionEvent))]
+pub struct UnhandledRejectionEvent( Reference );
+
+impl IEvent for UnhandledRejectionEvent {}
+impl IPromiseRejectionEvent for UnhandledRejectionEvent {}
+
+/// The `RejectionHandledEvent` is fired on the window when a rejection handler is
+/// attached to a promise after an [UnhandledRejectionEvent](struct.UnhandledRejectionEvent.html)
+/// was already fired for it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/rejectionhandled_event)
+// https://html.spec.whatwg.org/#event-rejectionhandled
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PromiseRejectionEvent")]
+#[reference(event = "rejectionhandled")]
+#[reference(subclass_of(Event, PromiseRejectionEvent))]
+pub struct RejectionHandledEvent( Reference );
+
+impl IEvent for RejectionHandledEvent {}
+impl IPromiseRejectionEvent for RejectionHandledEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use std::rc::Rc;
+    use std::cell::RefCell;
+    use super::*;
+    #[cfg(rust_nightly)]
+    use async_test;
+    use webapi::event::ConcreteEvent;
+    use webapi::event_target::IEventTarget;
+    use webapi::window::window;
+
+    #[test]
+    fn test_error_event() {
+        let events = Rc::new( RefCell::new( Vec::new() ) );
+        let handle = {
+            let events = events.clone();
+            window().add_event_listener( move |event: ErrorEvent| {
+                // Keep the error from being reported as uncaught.
+                event.prevent_default();
+                events.borrow_mut().push( event );
+            })
+        };
+
+        // Errors thrown by an inline script are reported while it's being inserted.
+        js! { @(no_return)
+            var script = document.createElement( "script" );
+            script.textContent = "throw new TypeError( 'oops' );";
+            document.body.appendChild( script );
+            document.body.removeChild( script );
+        }
+        handle.remove();
+
+        let events = events.borrow();
+        assert_eq!( events.len(), 1 );
+
+        let event = &events[ 0 ];
+        assert_eq!( event.event_type(), ErrorEvent::EVENT_TYPE );
+        assert!( event.message().contains( "oops" ) );
+        assert_eq!( js!( return @{event.error()}.name; ), "TypeError" );
+    }
+
+    #[test]
+    fn test_error_event_is_not_a_resource_error_event() {
+        use webapi::events::dom::ResourceErrorEvent;
+
+        // A `ResourceErrorEvent` listener on the window would be called with
+        // this event, and would fail to convert it.
+        assert_eq!( ErrorEvent::EVENT_TYPE, ResourceErrorEvent::EVENT_TYPE );
+
+        let event: Value = js!(
+            return new ErrorEvent( @{ErrorEvent::EVENT_TYPE}, { message: "oops" } );
+        );
+        let result: Result< ResourceErrorEvent, _ > = event.try_into();
+        assert!( result.is_err() );
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_unhandled_rejection_event< F: FnOnce() >( done: F ) {
+        use webapi::global::set_timeout;
+        use webapi::event_target::EventListenerHandle;
+
+        let handle: Rc< RefCell< Option< EventListenerHandle > > > = Rc::new( RefCell::new( None ) );
+        let mut done = Some( done );
+        *handle.borrow_mut() = Some( window().add_event_listener( {
+            let handle = handle.clone();
+            move |event: UnhandledRejectionEvent| {
+                if let Some( done ) = done.take() {
+                    event.prevent_default();
+                    assert_eq!( event.reason(), "reason" );
+
+                    // The listener can't be removed from within itself.
+                    let handle = handle.clone();
+                    set_timeout( move || {
+                        handle.borrow_mut().take().unwrap().remove();
+                        done();
+                    }, 0 );
+                }
+            }
+        }));
+
+        // A promise rejected without a handler, reported after the current task.
+        js! { @(no_return)
+            Promise.reject( "reason" );
+        }
+    }
+}
@@ -62,6 +62,36 @@ pub struct BlurEvent( Reference );
 impl IEvent for BlurEvent {}
 impl IFocusEvent for BlurEvent {}
 
+/// The `FocusInEvent` is fired when an element is about to receive focus. The main
+/// difference between this event and focus is that only the former bubbles, so it
+/// can be used to detect when focus enters any element of a subtree.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/focusin)
+// https://w3c.github.io/uievents/#event-type-focusin
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FocusEvent")]
+#[reference(event = "focusin")]
+#[reference(subclass_of(Event, FocusRelatedEvent))]
+pub struct FocusInEvent( Reference );
+
+impl IEvent for FocusInEvent {}
+impl IFocusEvent for FocusInEvent {}
+
+/// The `FocusOutEvent` is fired when an element is about to lose focus. The main
+/// difference between this event and blur is that only the former bubbles, so it
+/// can be used to detect when focus leaves a whole subtree.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/Events/focusout)
+// https://w3c.github.io/uievents/#event-type-focusout
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "FocusEvent")]
+#[reference(event = "focusout")]
+#[reference(subclass_of(Event, FocusRelatedEvent))]
+pub struct FocusOutEvent( Reference );
+
+impl IEvent for FocusOutEvent {}
+impl IFocusEvent for FocusOutEvent {}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -83,4 +113,52 @@ mod tests {
         ).try_into().unwrap();
         assert_eq!( event.event_type(), BlurEvent::EVENT_TYPE );
     }
+
+    #[test]
+    fn test_blur_event_related_target() {
+        use webapi::document::document;
+
+        let next = document().create_element( "input" ).unwrap();
+        let event: BlurEvent = js!(
+            return new FocusEvent( @{BlurEvent::EVENT_TYPE}, { relatedTarget: @{&next} } );
+        ).try_into().unwrap();
+        assert_eq!( event.related_target().unwrap().as_ref(), next.as_ref() );
+    }
+
+    #[test]
+    fn test_focus_in_bubbles() {
+        use std::rc::Rc;
+        use std::cell::Cell;
+        use webapi::document::document;
+        use webapi::event_target::IEventTarget;
+        use webapi::html_element::{IHtmlElement, HtmlElement};
+        use webapi::node::INode;
+
+        let parent = document().create_element( "div" ).unwrap();
+        let child: HtmlElement = document().create_element( "input" ).unwrap().try_into().unwrap();
+        parent.append_child( &child );
+        document().body().unwrap().append_child( &parent );
+
+        let focused_in = Rc::new( Cell::new( false ) );
+        let focused_out = Rc::new( Cell::new( false ) );
+        let handle_in = {
+            let focused_in = focused_in.clone();
+            parent.add_event_listener( move |_: FocusInEvent| focused_in.set( true ) )
+        };
+        let handle_out = {
+            let focused_out = focused_out.clone();
+            parent.add_event_listener( move |_: FocusOutEvent| focused_out.set( true ) )
+        };
+
+        child.focus();
+        assert!( focused_in.get() );
+        assert!( !focused_out.get() );
+
+        child.blur();
+        assert!( focused_out.get() );
+
+        handle_in.remove();
+        handle_out.remove();
+        document().body().unwrap().remove_child( &parent ).unwrap();
+    }
 }
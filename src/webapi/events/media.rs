@@ -0,0 +1,92 @@
+use webcore::value::Reference;
+use webapi::event::{IEvent, Event};
+
+/// The `PlayEvent` is fired on a media element when its playback
+/// has been started or resumed, e.g. after calling `play`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/play_event)
+// https://html.spec.whatwg.org/#event-media-play
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "play")]
+#[reference(subclass_of(Event))]
+pub struct PlayEvent( Reference );
+
+impl IEvent for PlayEvent {}
+
+/// The `PauseEvent` is fired on a media element when its playback has been paused.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/pause_event)
+// https://html.spec.whatwg.org/#event-media-pause
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "pause")]
+#[reference(subclass_of(Event))]
+pub struct PauseEvent( Reference );
+
+impl IEvent for PauseEvent {}
+
+/// The `EndedEvent` is fired on a media element when its playback
+/// has stopped because the end of the media was reached.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/ended_event)
+// https://html.spec.whatwg.org/#event-media-ended
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "ended")]
+#[reference(subclass_of(Event))]
+pub struct EndedEvent( Reference );
+
+impl IEvent for EndedEvent {}
+
+/// The `TimeUpdateEvent` is fired on a media element when its
+/// current playback position has changed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/timeupdate_event)
+// https://html.spec.whatwg.org/#event-media-timeupdate
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "timeupdate")]
+#[reference(subclass_of(Event))]
+pub struct TimeUpdateEvent( Reference );
+
+impl IEvent for TimeUpdateEvent {}
+
+/// The `CanPlayEvent` is fired on a media element when enough of the media
+/// has been loaded to start playing it, although it may need to stop for
+/// further buffering.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/canplay_event)
+// https://html.spec.whatwg.org/#event-media-canplay
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "canplay")]
+#[reference(subclass_of(Event))]
+pub struct CanPlayEvent( Reference );
+
+impl IEvent for CanPlayEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+    use webapi::event::ConcreteEvent;
+
+    #[test]
+    fn test_media_events() {
+        let event: PlayEvent = js!( return new Event( @{PlayEvent::EVENT_TYPE} ); ).try_into().unwrap();
+        assert_eq!( event.event_type(), "play" );
+
+        let event: PauseEvent = js!( return new Event( @{PauseEvent::EVENT_TYPE} ); ).try_into().unwrap();
+        assert_eq!( event.event_type(), "pause" );
+
+        let event: EndedEvent = js!( return new Event( @{EndedEvent::EVENT_TYPE} ); ).try_into().unwrap();
+        assert_eq!( event.event_type(), "ended" );
+
+        let event: TimeUpdateEvent = js!( return new Event( @{TimeUpdateEvent::EVENT_TYPE} ); ).try_into().unwrap();
+        assert_eq!( event.event_type(), "timeupdate" );
+
+        let event: CanPlayEvent = js!( return new Event( @{CanPlayEvent::EVENT_TYPE} ); ).try_into().unwrap();
+        assert_eq!( event.event_type(), "canplay" );
+    }
+}
@@ -1,9 +1,13 @@
+pub mod clipboard;
+pub mod custom;
 pub mod dom;
 pub mod drag;
+pub mod error;
 pub mod focus;
 pub mod gamepad;
 pub mod history;
 pub mod keyboard;
+pub mod media;
 pub mod mouse;
 pub mod pointer;
 pub mod progress;
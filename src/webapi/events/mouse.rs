@@ -140,7 +140,7 @@ pub trait IMouseEvent: IUiEvent {
     /// Returns the change in Y coordinate of the pointer between this event and the previous
     /// MouseMove event.
     ///
-    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/movementX)
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/movementY)
     // https://w3c.github.io/pointerlock/#extensions-to-the-mouseevent-interface
     #[inline]
     fn movement_y( &self ) -> i32 {
@@ -598,6 +598,12 @@ mod tests {
             return new MouseEvent( @{MouseMoveEvent::EVENT_TYPE} );
         ).try_into().unwrap();
         assert_eq!( event.event_type(), MouseMoveEvent::EVENT_TYPE );
+
+        let event: MouseMoveEvent = js!(
+            return new MouseEvent( @{MouseMoveEvent::EVENT_TYPE}, { movementX: 5, movementY: -3 } );
+        ).try_into().unwrap();
+        assert_eq!( event.movement_x(), 5 );
+        assert_eq!( event.movement_y(), -3 );
     }
 
     #[test]
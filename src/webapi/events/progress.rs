@@ -135,6 +135,20 @@ pub struct ProgressErrorEvent( Reference );
 impl IEvent for ProgressErrorEvent {}
 impl IProgressEvent for ProgressErrorEvent {}
 
+/// The `TimeoutEvent` is fired when the progress has been terminated
+/// because its preset time expired.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/timeout_event)
+// https://xhr.spec.whatwg.org/#event-xhr-timeout
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ProgressEvent")]
+#[reference(event = "timeout")]
+#[reference(subclass_of(Event, ProgressRelatedEvent))]
+pub struct TimeoutEvent( Reference );
+
+impl IEvent for TimeoutEvent {}
+impl IProgressEvent for TimeoutEvent {}
+
 #[cfg(all(test, feature = "web_test"))]
 mod tests {
     use super::*;
@@ -173,4 +187,13 @@ mod tests {
         ).try_into().unwrap();
         assert_eq!( event.event_type(), LoadEndEvent::EVENT_TYPE );
     }
+
+    #[test]
+    fn test_timeout_event() {
+        let event: TimeoutEvent = js!(
+            return new ProgressEvent( @{TimeoutEvent::EVENT_TYPE} );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), TimeoutEvent::EVENT_TYPE );
+        assert_eq!( TimeoutEvent::EVENT_TYPE, "timeout" );
+    }
 }
@@ -0,0 +1,508 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::blob::IBlob;
+use webapi::form_data::FormData;
+use webapi::array_buffer::ArrayBuffer;
+use webapi::headers::Headers;
+
+use webapi::error::TypeError;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The mode of a request, which determines whether cross-origin requests
+/// lead to valid responses and which properties of the response are readable.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Request/mode)
+// https://fetch.spec.whatwg.org/#requestmode
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RequestMode {
+    /// Only requests to the same origin are allowed.
+    SameOrigin,
+    /// Cross-origin requests are allowed, but the response is opaque.
+    NoCors,
+    /// Cross-origin requests are allowed if the server permits them through CORS.
+    Cors,
+    /// A mode used only by navigation requests.
+    Navigate
+}
+
+/// Determines whether the user agent should send cookies and other credentials with a request.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Request/credentials)
+// https://fetch.spec.whatwg.org/#requestcredentials
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RequestCredentials {
+    /// Never send credentials.
+    Omit,
+    /// Only send credentials if the URL is on the same origin as the calling script.
+    SameOrigin,
+    /// Always send credentials, even for cross-origin requests.
+    Include
+}
+
+/// Determines how a request interacts with the browser's HTTP cache.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Request/cache)
+// https://fetch.spec.whatwg.org/#requestcache
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RequestCache {
+    /// Use a fresh cached response, revalidate a stale one, or fetch from the network.
+    Default,
+    /// Always fetch from the network and don't update the cache.
+    NoStore,
+    /// Always fetch from the network and update the cache.
+    Reload,
+    /// Always revalidate a cached response with the server.
+    NoCache,
+    /// Use any cached response, even a stale one, and only fetch from the network if there is none.
+    ForceCache,
+    /// Use any cached response, even a stale one, and fail if there is none.
+    OnlyIfCached
+}
+
+/// Options which can be passed to [fetch](fn.fetch.html).
+///
+/// Use [RequestInit::builder](#method.builder) to build one.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/fetch#Parameters)
+// https://fetch.spec.whatwg.org/#requestinit
+#[derive(Clone, Debug)]
+pub struct RequestInit {
+    method: Option< String >,
+    headers: Vec< (String, String) >,
+    body: Option< Value >,
+    mode: Option< RequestMode >,
+    credentials: Option< RequestCredentials >,
+    cache: Option< RequestCache >
+}
+
+/// A builder for [RequestInit](struct.RequestInit.html).
+#[derive(Clone, Debug)]
+pub struct RequestInitBuilder( RequestInit );
+
+impl RequestInit {
+    /// Returns a builder for a new set of request options.
+    ///
+    /// Every option which isn't explicitly set uses the browser's default.
+    pub fn builder() -> RequestInitBuilder {
+        RequestInitBuilder( RequestInit {
+            method: None,
+            headers: Vec::new(),
+            body: None,
+            mode: None,
+            credentials: None,
+            cache: None
+        })
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    fn as_js( &self ) -> Value {
+        let header_names: Vec< &str > = self.headers.iter().map( |&(ref name, _)| name.as_str() ).collect();
+        let header_values: Vec< &str > = self.headers.iter().map( |&(_, ref value)| value.as_str() ).collect();
+        let mode = self.mode.map( request_mode_to_str );
+        let credentials = self.credentials.map( request_credentials_to_str );
+        let cache = self.cache.map( request_cache_to_str );
+
+        js!(
+            var init = {};
+            var method = @{&self.method};
+            var body = @{&self.body};
+            var mode = @{mode};
+            var credentials = @{credentials};
+            var cache = @{cache};
+            var header_names = @{header_names};
+            var header_values = @{header_values};
+
+            if( method !== null ) {
+                init.method = method;
+            }
+            if( header_names.length > 0 ) {
+                var headers = new Headers();
+                for( var i = 0; i < header_names.length; ++i ) {
+                    headers.append( header_names[ i ], header_values[ i ] );
+                }
+                init.headers = headers;
+            }
+            if( body !== null ) {
+                init.body = body;
+            }
+            if( mode !== null ) {
+                init.mode = mode;
+            }
+            if( credentials !== null ) {
+                init.credentials = credentials;
+            }
+            if( cache !== null ) {
+                init.cache = cache;
+            }
+
+            return init;
+        )
+    }
+}
+
+impl RequestInitBuilder {
+    /// Sets the request method, e.g. `"GET"` or `"POST"`.
+    pub fn method( mut self, method: &str ) -> Self {
+        self.0.method = Some( method.to_owned() );
+        self
+    }
+
+    /// Appends a header to the request. Calling this multiple times with
+    /// the same name sends all of the values.
+    pub fn header( mut self, name: &str, value: &str ) -> Self {
+        self.0.headers.push( (name.to_owned(), value.to_owned()) );
+        self
+    }
+
+    /// Appends all of the given headers to the request.
+    pub fn headers( mut self, headers: &Headers ) -> Self {
+        self.0.headers.extend( headers.iter() );
+        self
+    }
+
+    /// Appends an `Accept` header to the request, telling the server which
+    /// type of response the client expects.
+    pub fn accept( self, mime: &str ) -> Self {
+        self.header( "Accept", mime )
+    }
+
+    /// Appends an `Accept: application/json` header to the request.
+    pub fn accept_json( self ) -> Self {
+        self.accept( "application/json" )
+    }
+
+    /// Uses the given text as the request body.
+    pub fn body_text( mut self, body: &str ) -> Self {
+        self.0.body = Some( body.into() );
+        self
+    }
+
+    /// Uses the contents of the given blob as the request body.
+    pub fn body_blob< T: IBlob >( mut self, body: &T ) -> Self {
+        self.0.body = Some( body.as_ref().clone().into() );
+        self
+    }
+
+    /// Uses the given form data as a `multipart/form-data` request body.
+    pub fn body_form_data( mut self, body: &FormData ) -> Self {
+        self.0.body = Some( body.as_ref().clone().into() );
+        self
+    }
+
+    /// Uses the contents of the given buffer as the request body.
+    pub fn body_array_buffer( mut self, body: &ArrayBuffer ) -> Self {
+        self.0.body = Some( body.as_ref().clone().into() );
+        self
+    }
+
+    /// Sets the mode of the request.
+    pub fn mode( mut self, mode: RequestMode ) -> Self {
+        self.0.mode = Some( mode );
+        self
+    }
+
+    /// Sets whether credentials are sent with the request.
+    pub fn credentials( mut self, credentials: RequestCredentials ) -> Self {
+        self.0.credentials = Some( credentials );
+        self
+    }
+
+    /// Sets how the request interacts with the HTTP cache.
+    pub fn cache( mut self, cache: RequestCache ) -> Self {
+        self.0.cache = Some( cache );
+        self
+    }
+
+    /// Finishes building the request options.
+    pub fn build( self ) -> RequestInit {
+        self.0
+    }
+}
+
+/// The `Response` interface of the Fetch API represents the response to a request.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response)
+// https://fetch.spec.whatwg.org/#response-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Response")]
+pub struct Response( Reference );
+
+impl Response {
+    /// Returns the status code of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/status)
+    // https://fetch.spec.whatwg.org/#dom-response-status
+    pub fn status( &self ) -> u16 {
+        js!(
+            return @{self}.status;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the status code of the response is in the range 200-299.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/ok)
+    // https://fetch.spec.whatwg.org/#dom-response-ok
+    pub fn ok( &self ) -> bool {
+        js!(
+            return @{self}.ok;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the headers of the response.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/headers)
+    // https://fetch.spec.whatwg.org/#dom-response-headers
+    pub fn headers( &self ) -> Headers {
+        js!(
+            return @{self}.headers;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the body of the response has already been read.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Body/bodyUsed)
+    // https://fetch.spec.whatwg.org/#dom-body-bodyused
+    pub fn body_used( &self ) -> bool {
+        js!(
+            return @{self}.bodyUsed;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a copy of the response whose body can be read independently of this one.
+    ///
+    /// Unlike `Clone::clone`, which only copies the reference, this duplicates the
+    /// underlying body stream. Fails with a `TypeError` if the body has already been read.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Response/clone)
+    // https://fetch.spec.whatwg.org/#dom-response-clone
+    pub fn try_clone( &self ) -> Result< Response, TypeError > {
+        js_try!(
+            return @{self}.clone();
+        ).unwrap()
+    }
+
+    /// Reads the response body to completion and decodes it as text.
+    ///
+    /// Fails with a `TypeError` if the body has already been read.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Body/text)
+    // https://fetch.spec.whatwg.org/#dom-body-text
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn text( &self ) -> PromiseFuture< String, TypeError > {
+        js!(
+            return @{self}.text();
+        ).try_into().unwrap()
+    }
+
+    /// Reads the response body to completion and parses it as JSON.
+    ///
+    /// Fails with a `TypeError` if the body has already been read, or with a `SyntaxError`
+    /// if it isn't valid JSON, both wrapped in an [Error](error/struct.Error.html).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Body/json)
+    // https://fetch.spec.whatwg.org/#dom-body-json
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn json( &self ) -> PromiseFuture< Value > {
+        js!(
+            return @{self}.json();
+        ).try_into().unwrap()
+    }
+
+    /// Reads the response body to completion into an `ArrayBuffer`.
+    ///
+    /// Fails with a `TypeError` if the body has already been read.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Body/arrayBuffer)
+    // https://fetch.spec.whatwg.org/#dom-body-arraybuffer
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn array_buffer( &self ) -> PromiseFuture< ArrayBuffer, TypeError > {
+        js!(
+            return @{self}.arrayBuffer();
+        ).try_into().unwrap()
+    }
+}
+
+/// Starts fetching the given URL using the given request options.
+///
+/// Fails with a `TypeError` on network errors, e.g. when a cross-origin
+/// request isn't allowed; HTTP error statuses still resolve to a `Response`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/fetch)
+// https://fetch.spec.whatwg.org/#fetch-method
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+pub fn fetch( input: &str, init: RequestInit ) -> PromiseFuture< Response, TypeError > {
+    js!(
+        return fetch( @{input}, @{init.as_js()} );
+    ).try_into().unwrap()
+}
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+fn request_mode_to_str( mode: RequestMode ) -> &'static str {
+    match mode {
+        RequestMode::SameOrigin => "same-origin",
+        RequestMode::NoCors => "no-cors",
+        RequestMode::Cors => "cors",
+        RequestMode::Navigate => "navigate"
+    }
+}
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+fn request_credentials_to_str( credentials: RequestCredentials ) -> &'static str {
+    match credentials {
+        RequestCredentials::Omit => "omit",
+        RequestCredentials::SameOrigin => "same-origin",
+        RequestCredentials::Include => "include"
+    }
+}
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+fn request_cache_to_str( cache: RequestCache ) -> &'static str {
+    match cache {
+        RequestCache::Default => "default",
+        RequestCache::NoStore => "no-store",
+        RequestCache::Reload => "reload",
+        RequestCache::NoCache => "no-cache",
+        RequestCache::ForceCache => "force-cache",
+        RequestCache::OnlyIfCached => "only-if-cached"
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use webcore::promise_future::spawn_local;
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use futures_util::FutureExt;
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use futures_util::future::join3;
+
+    // Served by `ci/echo_server.js`.
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    const ECHO_URL: &str = "http://localhost:8765/echo";
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    fn post_json() -> RequestInit {
+        RequestInit::builder()
+            .method( "POST" )
+            .header( "Content-Type", "application/json" )
+            .header( "X-Requested-With", "stdweb" )
+            .body_text( r#"{"answer":42}"# )
+            .credentials( RequestCredentials::SameOrigin )
+            .mode( RequestMode::Cors )
+            .cache( RequestCache::NoStore )
+            .build()
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_request_init() {
+        let init = post_json().as_js();
+        let request: Value = js!(
+            return new Request( "http://localhost/echo", @{init} );
+        );
+
+        assert_eq!( js!( return @{&request}.method; ), "POST" );
+        assert_eq!( js!( return @{&request}.headers.get( "Content-Type" ); ), "application/json" );
+        assert_eq!( js!( return @{&request}.headers.get( "X-Requested-With" ); ), "stdweb" );
+        assert_eq!( js!( return @{&request}.credentials; ), "same-origin" );
+        assert_eq!( js!( return @{&request}.mode; ), "cors" );
+        assert_eq!( js!( return @{&request}.cache; ), "no-store" );
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_request_init_accept_json() {
+        let init = RequestInit::builder().accept_json().build().as_js();
+        let request: Value = js!(
+            return new Request( "http://localhost/echo", @{init} );
+        );
+
+        assert_eq!( js!( return @{&request}.headers.get( "Accept" ); ), "application/json" );
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_request_init_headers() {
+        let headers = Headers::new();
+        headers.append( "X-Custom", "a" ).unwrap();
+        headers.append( "X-Custom", "b" ).unwrap();
+
+        let init = RequestInit::builder().headers( &headers ).build().as_js();
+        let request: Value = js!(
+            return new Request( "http://localhost/echo", @{init} );
+        );
+
+        assert_eq!( js!( return @{&request}.headers.get( "X-Custom" ); ), "a, b" );
+    }
+
+    #[test]
+    fn test_response_headers() {
+        let response: Response = js!(
+            return new Response( "", { headers: { "Content-Type": "text/plain" } } );
+        ).try_into().unwrap();
+
+        assert_eq!( response.headers().get( "Content-Type" ), Some( "text/plain".to_owned() ) );
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_request_init_defaults() {
+        let init = RequestInit::builder().build().as_js();
+        assert_eq!( js!( return Object.keys( @{init} ).length; ), 0 );
+    }
+
+    #[test]
+    fn test_response_try_clone() {
+        let response: Response = js!( return new Response( "hello" ); ).try_into().unwrap();
+        let copy = response.try_clone().unwrap();
+        assert_ne!( response, copy );
+
+        js!( @(no_return) @{&copy}.text(); );
+        assert!( copy.body_used() );
+        assert!( !response.body_used() );
+
+        js!( @(no_return) @{&response}.text(); );
+        assert!( response.body_used() );
+        assert!( response.try_clone().is_err() );
+    }
+
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_response_body_readers< F: FnOnce() >( done: F ) {
+        let response: Response = js!( return new Response( "[1, 2]" ); ).try_into().unwrap();
+        let json = response.try_clone().unwrap().json();
+        let text = response.try_clone().unwrap().text();
+        let array_buffer = response.array_buffer();
+        assert!( response.body_used() );
+
+        spawn_local( join3( json, text, array_buffer ).map( move |(json, text, array_buffer)| {
+            let json: Vec< i32 > = json.unwrap().try_into().unwrap();
+            assert_eq!( json, vec![ 1, 2 ] );
+            assert_eq!( text.unwrap(), "[1, 2]" );
+            assert_eq!( Vec::< u8 >::from( array_buffer.unwrap() ), b"[1, 2]" );
+            done();
+        }));
+    }
+
+    #[cfg(all(rust_nightly, feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_fetch< F: FnOnce() >( done: F ) {
+        spawn_local( fetch( ECHO_URL, post_json() ).then( |response| {
+            let response = response.unwrap();
+            assert!( response.ok() );
+            response.json()
+        }).map( move |echo| {
+            let echo = echo.unwrap();
+            assert_eq!( js!( return @{&echo}.method; ), "POST" );
+            assert_eq!( js!( return @{&echo}.headers[ "content-type" ]; ), "application/json" );
+            assert_eq!( js!( return @{&echo}.headers[ "x-requested-with" ]; ), "stdweb" );
+            assert_eq!( js!( return @{&echo}.body; ), r#"{"answer":42}"# );
+            done();
+        }));
+    }
+}
@@ -3,6 +3,7 @@ use webcore::try_from::TryInto;
 use webapi::blob::IBlob;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::array_buffer::ArrayBuffer;
+use webapi::dom_exception::InvalidStateError;
 use private::TODO;
 
 /// The FileReader object lets web applications asynchronously read the contents of files
@@ -21,7 +22,8 @@ impl IEventTarget for FileReader {}
 /// The [result](struct.FileReader.html#method.result) of a read operation performed with a [FileReader](struct.File.html).
 #[derive(Clone, Debug)]
 pub enum FileReaderResult {
-    /// A string; a result of calling [FileReader::read_as_text](struct.FileReader.html#method.read_as_text).
+    /// A string; a result of calling [FileReader::read_as_text](struct.FileReader.html#method.read_as_text)
+    /// or [FileReader::read_as_data_url](struct.FileReader.html#method.read_as_data_url).
     String( String ),
 
     /// An [ArrayBuffer](struct.ArrayBuffer.html); a result of calling [FileReader::read_as_array_buffer](struct.FileReader.html#method.read_as_array_buffer).
@@ -63,11 +65,23 @@ impl FileReader {
     /// Starts reading the contents of the specified blob. Once finished
     /// the `result` attribute will contain the contents of the file as an [TypedArray](struct.ArrayBuffer.html).
     ///
+    /// Returns an `InvalidStateError` if another read operation is still in progress.
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FileReader/readAsArrayBuffer)
     // https://w3c.github.io/FileAPI/#ref-for-dfn-readAsArrayBuffer
-    pub fn read_as_array_buffer< T: IBlob >( &self, blob: &T ) -> Result< (), TODO > {
-        js!( @{self}.readAsArrayBuffer( @{blob.as_ref()} ); );
-        Ok(())
+    pub fn read_as_array_buffer< T: IBlob >( &self, blob: &T ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return) @{self}.readAsArrayBuffer( @{blob.as_ref()} ); ).unwrap()
+    }
+
+    /// Starts reading the contents of the specified blob. Once finished
+    /// the `result` attribute will contain the contents of the file as a `data:` URL.
+    ///
+    /// Returns an `InvalidStateError` if another read operation is still in progress.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/FileReader/readAsDataURL)
+    // https://w3c.github.io/FileAPI/#dfn-readAsDataURL
+    pub fn read_as_data_url< T: IBlob >( &self, blob: &T ) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return) @{self}.readAsDataURL( @{blob.as_ref()} ); ).unwrap()
     }
 
     /// Aborts the read operation. Upon return, the `ready_state` will be `Done`.
@@ -108,3 +122,53 @@ impl FileReader {
         }
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::blob::Blob;
+    #[cfg(rust_nightly)]
+    use async_test;
+
+    #[test]
+    fn test_read_while_loading() {
+        let blob: Blob = js!( return new Blob( [ new Uint8Array( [ 1, 2, 3 ] ) ] ); ).try_into().unwrap();
+        let reader = FileReader::new();
+        assert_eq!( reader.ready_state(), FileReaderReadyState::Empty );
+
+        reader.read_as_array_buffer( &blob ).unwrap();
+        assert_eq!( reader.ready_state(), FileReaderReadyState::Loading );
+        assert!( reader.read_as_data_url( &blob ).is_err() );
+        assert!( reader.read_as_array_buffer( &blob ).is_err() );
+
+        reader.abort();
+        reader.read_as_data_url( &blob ).unwrap();
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_read_as_array_buffer< F: FnOnce() >( done: F ) {
+        use webapi::events::progress::ProgressLoadEvent;
+
+        let blob: Blob = js!( return new Blob( [ new Uint8Array( [ 1, 2, 3 ] ) ] ); ).try_into().unwrap();
+        let reader = FileReader::new();
+
+        let mut done = Some( done );
+        reader.add_event_listener( {
+            let reader = reader.clone();
+            let blob = blob.clone();
+            move |_: ProgressLoadEvent| {
+                if let Some( done ) = done.take() {
+                    assert_eq!( reader.ready_state(), FileReaderReadyState::Done );
+                    match reader.result() {
+                        Some( FileReaderResult::ArrayBuffer( buffer ) ) => assert_eq!( buffer.len(), blob.len() ),
+                        result => panic!( "Unexpected result of a FileReader: {:?}", result )
+                    }
+                    done();
+                }
+            }
+        });
+
+        reader.read_as_array_buffer( &blob ).unwrap();
+    }
+}
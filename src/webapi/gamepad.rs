@@ -18,6 +18,9 @@ pub enum GamepadMappingType {
     NoMapping,
     /// This gamepad is mapped to the [Standard Gamepad layout](https://w3c.github.io/gamepad/#remapping)
     Standard,
+    /// This gamepad is an XR controller mapped to the
+    /// [XR Standard Gamepad layout](https://immersive-web.github.io/webxr-gamepads-module/#xr-standard-gamepad-mapping)
+    XrStandard,
 }
 
 impl TryFrom<Value> for GamepadMappingType {
@@ -28,6 +31,7 @@ impl TryFrom<Value> for GamepadMappingType {
         match value.as_ref() {
             "" => Ok(GamepadMappingType::NoMapping),
             "standard" => Ok(GamepadMappingType::Standard),
+            "xr-standard" => Ok(GamepadMappingType::XrStandard),
             s => Err(ConversionError::Custom(format!("invalid gamepad mapping type \"{}\"", s))),
         }
     }
@@ -77,6 +81,62 @@ impl GamepadButton {
     }
 }
 
+/// The position and orientation of a motion-tracked controller, like a VR controller.
+///
+/// Positions are in meters and orientations are quaternions in `[x, y, z, w]` order.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadPose)
+// https://w3c.github.io/gamepad/extensions.html#gamepadpose-interface
+#[derive(Clone, Debug, Eq, PartialEq, ReferenceType)]
+#[reference(instance_of = "GamepadPose")]
+pub struct GamepadPose( Reference );
+
+impl GamepadPose {
+    /// Can the controller track its position?
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadPose/hasPosition)
+    // https://w3c.github.io/gamepad/extensions.html#dom-gamepadpose-hasposition
+    #[inline]
+    pub fn has_position(&self) -> bool {
+        js!(
+            return @{self.as_ref()}.hasPosition;
+        ).try_into().unwrap()
+    }
+
+    /// Can the controller track its orientation?
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadPose/hasOrientation)
+    // https://w3c.github.io/gamepad/extensions.html#dom-gamepadpose-hasorientation
+    #[inline]
+    pub fn has_orientation(&self) -> bool {
+        js!(
+            return @{self.as_ref()}.hasOrientation;
+        ).try_into().unwrap()
+    }
+
+    /// The position of the controller as `[x, y, z]`, or `None` if it's unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadPose/position)
+    // https://w3c.github.io/gamepad/extensions.html#dom-gamepadpose-position
+    pub fn position(&self) -> Option<Vec<f64>> {
+        js!(
+            var position = @{self.as_ref()}.position;
+            return position ? Array.from(position) : null;
+        ).try_into().unwrap()
+    }
+
+    /// The orientation of the controller as a `[x, y, z, w]` quaternion, or `None` if it's unknown.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/GamepadPose/orientation)
+    // https://w3c.github.io/gamepad/extensions.html#dom-gamepadpose-orientation
+    pub fn orientation(&self) -> Option<Vec<f64>> {
+        js!(
+            var orientation = @{self.as_ref()}.orientation;
+            return orientation ? Array.from(orientation) : null;
+        ).try_into().unwrap()
+    }
+}
+
 /// An individual gamepad/controller.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad)
@@ -142,6 +202,17 @@ impl Gamepad {
         ).try_into().unwrap()
     }
 
+    /// The pose of a motion-tracked controller, or `None` if the gamepad
+    /// isn't tracked or the browser doesn't support the Gamepad Extensions.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/pose)
+    // https://w3c.github.io/gamepad/extensions.html#dom-gamepad-pose
+    pub fn pose(&self) -> Option<GamepadPose> {
+        js!(
+            return @{self.as_ref()}.pose || null;
+        ).try_into().unwrap()
+    }
+
     /// Array of values for all axes of the gamepad.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad/axes)
@@ -175,6 +246,38 @@ impl Gamepad {
             return Array.from(navigator.getGamepads());
         ).try_into().unwrap()
     }
+
+    /// Retrieve the `index` of every currently connected gamepad.
+    ///
+    /// Together with [get](#method.get) this allows polling individual gamepads
+    /// without converting the whole gamepad list on every frame.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getGamepads)
+    // https://w3c.github.io/gamepad/#dom-navigator-getgamepads
+    pub fn connected_indices() -> Vec<i32> {
+        js!(
+            var pads = navigator.getGamepads();
+            var indices = [];
+            for (var i = 0; i < pads.length; ++i) {
+                if (pads[i] && pads[i].connected) {
+                    indices.push(pads[i].index);
+                }
+            }
+            return indices;
+        ).try_into().unwrap()
+    }
+
+    /// Retrieve the gamepad with the given `index`, if there is one.
+    ///
+    /// Like [get_all](#method.get_all), this must be called each frame to get updated state in Chrome.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getGamepads)
+    // https://w3c.github.io/gamepad/#dom-navigator-getgamepads
+    pub fn get(index: i32) -> Option<Gamepad> {
+        js!(
+            return navigator.getGamepads()[@{index}];
+        ).try_into().unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -192,11 +295,48 @@ mod tests {
         };
 
         assert_eq!(to_mapping("standard".into()), Ok(GamepadMappingType::Standard));
+        assert_eq!(to_mapping("xr-standard".into()), Ok(GamepadMappingType::XrStandard));
         assert_eq!(to_mapping("".into()), Ok(GamepadMappingType::NoMapping));
         assert!(to_mapping("fakemapping".into()).is_err());
         assert!(to_mapping(Value::Null).is_err());
     }
 
+    #[test]
+    fn test_gamepad_mapping_is_exhaustive() {
+        let to_mapping = |v: Value| -> Result<GamepadMappingType, ConversionError> {
+            v.try_into()
+        };
+
+        // Fails to compile if a mapping type is added without handling it here.
+        let name = |mapping: GamepadMappingType| match mapping {
+            GamepadMappingType::NoMapping => "",
+            GamepadMappingType::Standard => "standard",
+            GamepadMappingType::XrStandard => "xr-standard",
+        };
+
+        for mapping in &[GamepadMappingType::NoMapping, GamepadMappingType::Standard, GamepadMappingType::XrStandard] {
+            assert_eq!(to_mapping(name(*mapping).into()), Ok(*mapping));
+        }
+    }
+
     // most of the Gamepad API is not testable,
     // because Gamepad and GamepadButton are not constructible
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod web_tests {
+    use super::Gamepad;
+
+    #[test]
+    fn test_get_matches_get_all() {
+        let first = Gamepad::get_all().into_iter().next().and_then(|pad| pad);
+        assert_eq!(Gamepad::get(0), first);
+    }
+
+    #[test]
+    fn test_connected_indices_are_gettable() {
+        for index in Gamepad::connected_indices() {
+            assert_eq!(Gamepad::get(index).unwrap().index(), index);
+        }
+    }
+}
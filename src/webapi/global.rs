@@ -1,3 +1,6 @@
+use webcore::value::Value;
+use webcore::once::Once;
+use webcore::serialization::JsSerialize;
 use webapi::window::window;
 use webapi::window_or_worker::IWindowOrWorker;
 
@@ -6,6 +9,19 @@ pub fn set_timeout< F: FnOnce() + 'static >( callback: F, timeout: u32 ) {
     window().set_timeout( callback, timeout );
 }
 
+/// Sets a timer which executes a function once after the timer expires,
+/// passing it `value` after a round trip through JavaScript.
+///
+/// The `value` is serialized when the timer is set, not when it expires.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/setTimeout)
+// https://html.spec.whatwg.org/#windoworworkerglobalscope-mixin:dom-settimeout
+pub fn set_timeout_with_value< T: JsSerialize, F: FnOnce( Value ) + 'static >( value: T, callback: F, timeout: u32 ) {
+    js! { @(no_return)
+        setTimeout( @{Once( callback )}, @{timeout}, @{value} );
+    }
+}
+
 /// An alias for [window.alert](struct.Window.html#method.alert).
 pub fn alert( message: &str ) {
     window().alert( message );
@@ -15,3 +31,17 @@ pub fn alert( message: &str ) {
 pub fn confirm( message: &str ) -> bool {
     return window().confirm( message );
 }
+
+#[cfg(all(test, feature = "web_test", rust_nightly))]
+mod tests {
+    use super::*;
+    use async_test;
+
+    #[async_test]
+    fn test_set_timeout_with_value< F: FnOnce() >( done: F ) {
+        set_timeout_with_value( 42, move |value| {
+            assert_eq!( value, Value::Number( 42.into() ) );
+            done();
+        }, 0 );
+    }
+}
@@ -0,0 +1,193 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::error::TypeError;
+
+/// The `Headers` interface represents a list of HTTP headers, e.g. those of a
+/// fetch [Response](struct.Response.html).
+///
+/// Header names are case-insensitive.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers)
+// https://fetch.spec.whatwg.org/#headers-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Headers")]
+pub struct Headers( Reference );
+
+impl Headers {
+    /// Creates a new empty list of headers.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/Headers)
+    // https://fetch.spec.whatwg.org/#dom-headers
+    pub fn new() -> Self {
+        js!(
+            return new Headers();
+        ).try_into().unwrap()
+    }
+
+    /// Appends a value to the given header, keeping any values it already has.
+    ///
+    /// Fails with a `TypeError` if the name or the value isn't a valid header
+    /// name or value, or if the headers are immutable.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/append)
+    // https://fetch.spec.whatwg.org/#dom-headers-append
+    pub fn append( &self, name: &str, value: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.append( @{name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Sets the value of the given header, replacing any values it already has.
+    ///
+    /// Fails with a `TypeError` if the name or the value isn't a valid header
+    /// name or value, or if the headers are immutable.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/set)
+    // https://fetch.spec.whatwg.org/#dom-headers-set
+    pub fn set( &self, name: &str, value: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.set( @{name}, @{value} );
+        ).unwrap()
+    }
+
+    /// Removes the given header.
+    ///
+    /// Fails with a `TypeError` if the name isn't a valid header name,
+    /// or if the headers are immutable.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/delete)
+    // https://fetch.spec.whatwg.org/#dom-headers-delete
+    pub fn delete( &self, name: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.delete( @{name} );
+        ).unwrap()
+    }
+
+    /// Returns all of the values of the given header joined with `", "`,
+    /// or `None` if the header isn't present.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/get)
+    // https://fetch.spec.whatwg.org/#dom-headers-get
+    pub fn get( &self, name: &str ) -> Option< String > {
+        js!(
+            try {
+                return @{self}.get( @{name} );
+            } catch( error ) {
+                // An invalid name can't be present.
+                return null;
+            }
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the given header is present.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/has)
+    // https://fetch.spec.whatwg.org/#dom-headers-has
+    pub fn has( &self, name: &str ) -> bool {
+        js!(
+            try {
+                return @{self}.has( @{name} );
+            } catch( error ) {
+                return false;
+            }
+        ).try_into().unwrap()
+    }
+
+    /// Returns an iterator over the `(name, value)` pairs of the headers.
+    ///
+    /// Names are lowercased and sorted, and the values of headers
+    /// which were appended multiple times are combined.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Headers/entries)
+    // https://fetch.spec.whatwg.org/#concept-header-list-sort-and-combine
+    pub fn iter( &self ) -> HeadersIter {
+        HeadersIter {
+            iter: js!( return @{self}.entries(); )
+        }
+    }
+}
+
+impl Default for Headers {
+    #[inline]
+    fn default() -> Self {
+        Headers::new()
+    }
+}
+
+impl< 'a > IntoIterator for &'a Headers {
+    type Item = (String, String);
+    type IntoIter = HeadersIter;
+
+    #[inline]
+    fn into_iter( self ) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the name-value pairs of [Headers](struct.Headers.html).
+///
+/// This is created by the [Headers::iter](struct.Headers.html#method.iter) method.
+#[derive(Debug)]
+pub struct HeadersIter {
+    iter: Value
+}
+
+impl Iterator for HeadersIter {
+    type Item = (String, String);
+    fn next( &mut self ) -> Option< Self::Item > {
+        let entry = js!( return @{&self.iter}.next(); );
+        let is_done: bool = js!( return @{&entry}.done; ).try_into().unwrap();
+        if is_done {
+            return None;
+        }
+
+        let name = js!( return @{&entry}.value[0]; ).try_into().unwrap();
+        let value = js!( return @{&entry}.value[1]; ).try_into().unwrap();
+        Some( (name, value) )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers() {
+        let headers = Headers::new();
+        headers.append( "Content-Type", "text/plain" ).unwrap();
+        headers.append( "X-Custom", "a" ).unwrap();
+        headers.append( "x-custom", "b" ).unwrap();
+
+        assert_eq!( headers.get( "content-type" ), Some( "text/plain".to_owned() ) );
+        assert_eq!( headers.get( "X-Custom" ), Some( "a, b".to_owned() ) );
+        assert_eq!( headers.get( "Accept" ), None );
+        assert!( headers.has( "X-CUSTOM" ) );
+
+        headers.set( "X-Custom", "c" ).unwrap();
+        assert_eq!( headers.get( "X-Custom" ), Some( "c".to_owned() ) );
+
+        let entries: Vec< _ > = headers.iter().collect();
+        assert_eq!( entries, vec![
+            ("content-type".to_owned(), "text/plain".to_owned()),
+            ("x-custom".to_owned(), "c".to_owned())
+        ]);
+
+        headers.delete( "Content-Type" ).unwrap();
+        assert!( !headers.has( "Content-Type" ) );
+
+        assert!( headers.append( "Invalid Name", "a" ).is_err() );
+        assert_eq!( headers.get( "Invalid Name" ), None );
+        assert!( !headers.has( "Invalid Name" ) );
+    }
+
+    #[test]
+    fn test_headers_in_js() {
+        let headers = Headers::new();
+        headers.set( "Accept", "application/json" ).unwrap();
+
+        let request: Value = js!(
+            return new Request( "http://localhost/echo", { headers: @{&headers} } );
+        );
+        assert_eq!( js!( return @{&request}.headers.get( "Accept" ); ), "application/json" );
+    }
+}
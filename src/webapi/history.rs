@@ -9,6 +9,18 @@ use private::TODO;
 #[reference(instance_of = "History")]
 pub struct History(Reference);
 
+/// Whether the browser restores the scroll position when navigating to a history entry.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History/scrollRestoration)
+// https://html.spec.whatwg.org/#scrollrestoration
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ScrollRestoration {
+    /// The browser restores the scroll position. (default)
+    Auto,
+    /// The page is responsible for restoring the scroll position.
+    Manual
+}
+
 impl History {
     /// Adds a new entry to history.
     ///
@@ -96,4 +108,57 @@ impl History {
             return @{self}.length;
         ).try_into().unwrap()
     }
+
+    /// Returns whether the browser restores the scroll position when navigating to a history entry.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History/scrollRestoration)
+    // https://html.spec.whatwg.org/#dom-history-scroll-restoration
+    pub fn scroll_restoration(&self) -> ScrollRestoration {
+        let value: String = js!(
+            return @{self}.scrollRestoration;
+        ).try_into().unwrap();
+
+        match value.as_str() {
+            "manual" => ScrollRestoration::Manual,
+            _ => ScrollRestoration::Auto
+        }
+    }
+
+    /// Sets whether the browser restores the scroll position when navigating to a history entry.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/History/scrollRestoration)
+    // https://html.spec.whatwg.org/#dom-history-scroll-restoration
+    pub fn set_scroll_restoration(&self, value: ScrollRestoration) {
+        let value = match value {
+            ScrollRestoration::Auto => "auto",
+            ScrollRestoration::Manual => "manual"
+        };
+
+        js! { @(no_return)
+            @{self}.scrollRestoration = @{value};
+        };
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+
+    #[test]
+    fn test_scroll_restoration() {
+        let history = window().history();
+        history.set_scroll_restoration(ScrollRestoration::Manual);
+        assert_eq!(history.scroll_restoration(), ScrollRestoration::Manual);
+        history.set_scroll_restoration(ScrollRestoration::Auto);
+        assert_eq!(history.scroll_restoration(), ScrollRestoration::Auto);
+    }
+
+    #[test]
+    fn test_replace_state() {
+        let history = window().history();
+        let length = history.len();
+        history.replace_state((), "", None).unwrap();
+        assert_eq!(history.len(), length);
+    }
 }
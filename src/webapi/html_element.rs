@@ -4,6 +4,22 @@ use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::element::{IElement, Element};
 use webapi::string_map::StringMap;
+use webapi::css_style_declaration::CssStyleDeclaration;
+use webapi::dom_exception::SyntaxError;
+
+/// Determines whether scrolling animates smoothly or happens instantly.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScrollToOptions/behavior)
+// https://drafts.csswg.org/cssom-view/#enumdef-scrollbehavior
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// Uses the `scroll-behavior` CSS property of the element. (default)
+    Auto,
+    /// Scrolls in a smooth fashion.
+    Smooth,
+    /// Scrolls in a single jump.
+    Instant
+}
 
 /// Represents a rectangle.
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMRect)
@@ -125,15 +141,28 @@ pub trait IHtmlElement: IElement {
         }
     }
 
-    /// Returns the size of an element and its position relative to the viewport.
-    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect)
-    // https://drafts.csswg.org/cssom-view/#ref-for-dom-element-getboundingclientrect
-    fn get_bounding_client_rect( &self ) -> Rect {
-        js! (
-            return @{self.as_ref()}.getBoundingClientRect();
+    /// Returns the inline style of the element, i.e. the declarations
+    /// in its `style` attribute.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/style)
+    // https://drafts.csswg.org/cssom/#dom-elementcssinlinestyle-style
+    fn style( &self ) -> CssStyleDeclaration {
+        js!(
+            return @{self.as_ref()}.style;
         ).try_into().unwrap()
     }
 
+    /// Sets the CSS custom property (CSS variable) `--name` in the element's inline style,
+    /// e.g. `set_css_var("accent", "#ff0000")` makes `var(--accent)` available
+    /// to the element and its descendants. A leading `--` in `name` is optional.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/CSS/--*)
+    // https://drafts.csswg.org/css-variables/#defining-variables
+    fn set_css_var( &self, name: &str, value: &str ) {
+        let name = if name.starts_with( "--" ) { name.to_owned() } else { format!( "--{}", name ) };
+        self.style().set_property( &name, value ).unwrap();
+    }
+
     /// Returns the layout width of an element. Typically, an element's offsetWidth is a
     /// measurement which includes the element borders, the element horizontal padding, the
     /// element vertical scrollbar (if present, if rendered) and the element CSS width.
@@ -153,6 +182,55 @@ pub trait IHtmlElement: IElement {
         ).try_into().unwrap()
     }
 
+    /// Returns the layout width and height of the element, i.e. its
+    /// [offset_width](#method.offset_width) and [offset_height](#method.offset_height),
+    /// in a single call.
+    // https://drafts.csswg.org/cssom-view/#ref-for-dom-htmlelement-offsetwidth
+    fn size( &self ) -> (f64, f64) {
+        let size: Vec< f64 > = js!(
+            var element = @{self.as_ref()};
+            return [element.offsetWidth, element.offsetHeight];
+        ).try_into().unwrap();
+
+        (size[ 0 ], size[ 1 ])
+    }
+
+    /// Scrolls the contents of the element to the given coordinates.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollTo)
+    // https://drafts.csswg.org/cssom-view/#dom-element-scrollto
+    fn scroll_to( &self, x: f64, y: f64 ) {
+        self.scroll_to_with_behavior( x, y, ScrollBehavior::Auto )
+    }
+
+    /// Scrolls the contents of the element to the given coordinates using the given behavior.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollTo)
+    // https://drafts.csswg.org/cssom-view/#dom-element-scrollto
+    fn scroll_to_with_behavior( &self, x: f64, y: f64, behavior: ScrollBehavior ) {
+        js! { @(no_return)
+            @{self.as_ref()}.scrollTo( { left: @{x}, top: @{y}, behavior: @{scroll_behavior_to_str( behavior )} } );
+        }
+    }
+
+    /// Scrolls the contents of the element by the given amounts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollBy)
+    // https://drafts.csswg.org/cssom-view/#dom-element-scrollby
+    fn scroll_by( &self, dx: f64, dy: f64 ) {
+        self.scroll_by_with_behavior( dx, dy, ScrollBehavior::Auto )
+    }
+
+    /// Scrolls the contents of the element by the given amounts using the given behavior.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollBy)
+    // https://drafts.csswg.org/cssom-view/#dom-element-scrollby
+    fn scroll_by_with_behavior( &self, dx: f64, dy: f64, behavior: ScrollBehavior ) {
+        js! { @(no_return)
+            @{self.as_ref()}.scrollBy( { left: @{dx}, top: @{dy}, behavior: @{scroll_behavior_to_str( behavior )} } );
+        }
+    }
+
     /// A property which represents the "rendered" text content of a node and its descendants.
     /// It approximates the text the user would get if they highlighted the contents of the element
     /// with the cursor and then copied to the clipboard.
@@ -167,6 +245,114 @@ pub trait IHtmlElement: IElement {
             return @{self.as_ref()}.innerText;
         ).try_into().unwrap()
     }
+
+    /// Returns whether the element is inert, i.e. whether it and its descendants
+    /// are excluded from user interaction, focus and the accessibility tree.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/inert)
+    // https://html.spec.whatwg.org/#dom-inert
+    fn inert( &self ) -> bool {
+        js!(
+            return !!@{self.as_ref()}.inert;
+        ).try_into().unwrap()
+    }
+
+    /// Makes the element and its descendants inert, e.g. to disable the
+    /// page content behind a modal dialog.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/inert)
+    // https://html.spec.whatwg.org/#dom-inert
+    fn set_inert( &self, inert: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.inert = @{inert};
+        }
+    }
+
+    /// Returns the position of the element in the sequential keyboard navigation order.
+    ///
+    /// A negative value means the element is focusable but can't be reached with
+    /// the Tab key, and `0` means it follows the document order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/tabIndex)
+    // https://html.spec.whatwg.org/#dom-tabindex
+    fn tab_index( &self ) -> i32 {
+        js!(
+            return @{self.as_ref()}.tabIndex;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the position of the element in the sequential keyboard navigation order.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/tabIndex)
+    // https://html.spec.whatwg.org/#dom-tabindex
+    fn set_tab_index( &self, tab_index: i32 ) {
+        js! { @(no_return)
+            @{self.as_ref()}.tabIndex = @{tab_index};
+        }
+    }
+
+    /// Returns whether the element can be dragged with the HTML Drag and Drop API.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/draggable)
+    // https://html.spec.whatwg.org/#dom-draggable
+    fn draggable( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.draggable;
+        ).try_into().unwrap()
+    }
+
+    /// Sets whether the element can be dragged with the HTML Drag and Drop API.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/draggable)
+    // https://html.spec.whatwg.org/#dom-draggable
+    fn set_draggable( &self, draggable: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.draggable = @{draggable};
+        }
+    }
+
+    /// Returns the `contentEditable` state of the element: `"true"`, `"false"`,
+    /// `"plaintext-only"`, or `"inherit"` if it's inherited from the parent.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+    // https://html.spec.whatwg.org/#dom-contenteditable
+    fn content_editable( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.contentEditable;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the `contentEditable` state of the element.
+    ///
+    /// Fails with a `SyntaxError` if the value isn't one of `"true"`, `"false"`,
+    /// `"plaintext-only"` or `"inherit"` (case-insensitive).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/contentEditable)
+    // https://html.spec.whatwg.org/#dom-contenteditable
+    fn set_content_editable( &self, value: &str ) -> Result< (), SyntaxError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.contentEditable = @{value};
+        ).unwrap()
+    }
+
+    /// Returns `true` if the contents of the element can be edited by the user,
+    /// either because of its own `contentEditable` state or an inherited one.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/isContentEditable)
+    // https://html.spec.whatwg.org/#dom-iscontenteditable
+    fn is_content_editable( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.isContentEditable;
+        ).try_into().unwrap()
+    }
+}
+
+pub(crate) fn scroll_behavior_to_str( behavior: ScrollBehavior ) -> &'static str {
+    match behavior {
+        ScrollBehavior::Auto => "auto",
+        ScrollBehavior::Smooth => "smooth",
+        ScrollBehavior::Instant => "instant"
+    }
 }
 
 /// A reference to a JavaScript object which implements the [IHtmlElement](trait.IHtmlElement.html)
@@ -208,4 +394,107 @@ mod tests {
         element.append_child(&text("foo"));
         assert_eq!(element.inner_text(), "foo foo");
     }
+
+    #[test]
+    fn test_size() {
+        use webapi::document::document;
+
+        let element: HtmlElement = div().try_into().unwrap();
+        element.set_attribute("style", "width: 120px; height: 30px; padding: 5px").unwrap();
+        document().body().unwrap().append_child(&element);
+
+        assert_eq!(element.size(), (element.offset_width() as f64, element.offset_height() as f64));
+        assert_eq!(element.size(), (130.0, 40.0));
+
+        document().body().unwrap().remove_child(&element).unwrap();
+    }
+
+    #[test]
+    fn test_scroll_to_and_scroll_by() {
+        use webapi::document::document;
+
+        let container: HtmlElement = div().try_into().unwrap();
+        container.set_attribute("style", "height: 100px; overflow: auto").unwrap();
+        let content = div();
+        content.set_attribute("style", "height: 1000px").unwrap();
+        container.append_child(&content);
+        document().body().unwrap().append_child(&container);
+
+        container.scroll_to(0.0, 50.0);
+        assert_eq!(container.scroll_top(), 50.0);
+
+        container.scroll_by_with_behavior(0.0, 20.0, ScrollBehavior::Instant);
+        assert_eq!(container.scroll_top(), 70.0);
+
+        document().body().unwrap().remove_child(&container).unwrap();
+    }
+
+    #[test]
+    fn test_inert() {
+        use webapi::document::document;
+
+        let container: HtmlElement = div().try_into().unwrap();
+        let input: HtmlElement = document().create_element("input").unwrap().try_into().unwrap();
+        container.append_child(&input);
+        document().body().unwrap().append_child(&container);
+
+        assert!(!container.inert());
+        container.set_inert(true);
+        assert!(container.inert());
+        assert!(container.has_attribute("inert"));
+
+        let active = document().active_element();
+        input.focus();
+        assert_eq!(document().active_element(), active);
+
+        container.set_inert(false);
+        input.focus();
+        assert_eq!(document().active_element(), Some(input.clone().into()));
+
+        input.blur();
+        document().body().unwrap().remove_child(&container).unwrap();
+    }
+
+    #[test]
+    fn test_content_editable() {
+        use webapi::document::document;
+
+        let editor: HtmlElement = div().try_into().unwrap();
+        let child: HtmlElement = div().try_into().unwrap();
+        editor.append_child(&child);
+        document().body().unwrap().append_child(&editor);
+
+        assert_eq!(editor.content_editable(), "inherit");
+        assert!(!editor.is_content_editable());
+
+        editor.set_content_editable("true").unwrap();
+        assert_eq!(editor.content_editable(), "true");
+        assert!(editor.is_content_editable());
+        assert_eq!(child.content_editable(), "inherit");
+        assert!(child.is_content_editable());
+
+        assert!(editor.set_content_editable("maybe").is_err());
+        assert_eq!(editor.content_editable(), "true");
+
+        editor.set_content_editable("false").unwrap();
+        assert!(!child.is_content_editable());
+
+        document().body().unwrap().remove_child(&editor).unwrap();
+    }
+
+    #[test]
+    fn test_tab_index_and_draggable() {
+        let element: HtmlElement = div().try_into().unwrap();
+        assert_eq!(element.tab_index(), -1);
+        element.set_tab_index(3);
+        assert_eq!(element.tab_index(), 3);
+        assert_eq!(element.get_attribute("tabindex"), Some("3".to_owned()));
+
+        assert!(!element.draggable());
+        element.set_draggable(true);
+        assert!(element.draggable());
+        assert_eq!(element.get_attribute("draggable"), Some("true".to_owned()));
+        element.set_draggable(false);
+        assert!(!element.draggable());
+    }
 }
@@ -0,0 +1,100 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::html_elements::media::{IMediaElement, MediaElement};
+
+/// The HTML audio element is used to play sound, e.g. from an `<audio>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement)
+// https://html.spec.whatwg.org/#htmlaudioelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLAudioElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement, MediaElement))]
+pub struct AudioElement( Reference );
+
+impl IEventTarget for AudioElement {}
+impl INode for AudioElement {}
+impl IElement for AudioElement {}
+impl IHtmlElement for AudioElement {}
+impl IMediaElement for AudioElement {}
+
+impl AudioElement {
+    /// Constructs a new AudioElement which isn't attached to the document.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement/Audio)
+    // https://html.spec.whatwg.org/#dom-audio
+    pub fn new() -> AudioElement {
+        js!(
+            return new Audio();
+        ).try_into().unwrap()
+    }
+
+    /// Constructs a new AudioElement which starts loading the given URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLAudioElement/Audio)
+    // https://html.spec.whatwg.org/#dom-audio
+    pub fn with_src( src: &str ) -> AudioElement {
+        js!(
+            return new Audio( @{src} );
+        ).try_into().unwrap()
+    }
+}
+
+impl Default for AudioElement {
+    #[inline]
+    fn default() -> Self {
+        AudioElement::new()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
+
+    #[test]
+    fn test_audio_element() {
+        let audio = AudioElement::new();
+        assert!( audio.paused() );
+        assert_eq!( audio.current_time(), 0.0 );
+        assert!( audio.duration().is_nan() );
+        assert_eq!( audio.src(), "" );
+
+        audio.set_muted( true );
+        assert!( audio.muted() );
+
+        audio.set_volume( 0.5 ).unwrap();
+        assert_eq!( audio.volume(), 0.5 );
+        assert!( audio.set_volume( 1.5 ).is_err() );
+        assert_eq!( audio.volume(), 0.5 );
+
+        audio.pause();
+        assert!( audio.paused() );
+
+        let media: MediaElement = audio.into();
+        media.set_src( "http://localhost/sound.ogg" );
+        assert_eq!( media.src(), "http://localhost/sound.ogg" );
+
+        let audio = AudioElement::with_src( "http://localhost/sound.ogg" );
+        assert_eq!( audio.src(), "http://localhost/sound.ogg" );
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_play_without_src< F: FnOnce() >( done: F ) {
+        use webcore::promise_future::spawn_local;
+        use futures_util::FutureExt;
+
+        let audio = AudioElement::default();
+        audio.set_muted( true );
+
+        spawn_local( audio.play().map( move |result| {
+            assert!( result.is_err() );
+            done();
+        }));
+    }
+}
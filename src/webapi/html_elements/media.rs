@@ -0,0 +1,164 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::dom_exception::IndexSizeError;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The `IMediaElement` interface adds the properties and methods needed
+/// to support basic media-related capabilities that are common to audio and video.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement)
+// https://html.spec.whatwg.org/#htmlmediaelement
+pub trait IMediaElement: IHtmlElement {
+    /// Starts or resumes the playback of the media.
+    ///
+    /// The returned future resolves once playback has started, or to an `Err`
+    /// if the browser refuses to play, e.g. because autoplay isn't allowed
+    /// outside of a user interaction.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/play)
+    // https://html.spec.whatwg.org/#dom-media-play
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    fn play( &self ) -> PromiseFuture< () > {
+        js!(
+            var promise = @{self.as_ref()}.play();
+            // Older browsers don't return a promise.
+            return promise || Promise.resolve();
+        ).try_into().unwrap()
+    }
+
+    /// Pauses the playback of the media.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/pause)
+    // https://html.spec.whatwg.org/#dom-media-pause
+    fn pause( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.pause();
+        }
+    }
+
+    /// Returns `true` if the playback of the media is paused.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/paused)
+    // https://html.spec.whatwg.org/#dom-media-paused
+    fn paused( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.paused;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the current playback position, in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/currentTime)
+    // https://html.spec.whatwg.org/#dom-media-currenttime
+    fn current_time( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.currentTime;
+        ).try_into().unwrap()
+    }
+
+    /// Seeks to the given playback position, in seconds.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/currentTime)
+    // https://html.spec.whatwg.org/#dom-media-currenttime
+    fn set_current_time( &self, time: f64 ) {
+        js! { @(no_return)
+            @{self.as_ref()}.currentTime = @{time};
+        }
+    }
+
+    /// Returns the length of the media, in seconds.
+    ///
+    /// This is `NaN` if no media is loaded yet, and infinity for live streams.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/duration)
+    // https://html.spec.whatwg.org/#dom-media-duration
+    fn duration( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.duration;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the volume of the audio, from `0.0` (silent) to `1.0` (loudest).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/volume)
+    // https://html.spec.whatwg.org/#dom-media-volume
+    fn volume( &self ) -> f64 {
+        js!(
+            return @{self.as_ref()}.volume;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the volume of the audio, from `0.0` (silent) to `1.0` (loudest).
+    ///
+    /// Fails with an `IndexSizeError` if the volume is outside of that range.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/volume)
+    // https://html.spec.whatwg.org/#dom-media-volume
+    fn set_volume( &self, volume: f64 ) -> Result< (), IndexSizeError > {
+        js_try!( @(no_return)
+            @{self.as_ref()}.volume = @{volume};
+        ).unwrap()
+    }
+
+    /// Returns `true` if the audio is muted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/muted)
+    // https://html.spec.whatwg.org/#dom-media-muted
+    fn muted( &self ) -> bool {
+        js!(
+            return @{self.as_ref()}.muted;
+        ).try_into().unwrap()
+    }
+
+    /// Mutes or unmutes the audio.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/muted)
+    // https://html.spec.whatwg.org/#dom-media-muted
+    fn set_muted( &self, muted: bool ) {
+        js! { @(no_return)
+            @{self.as_ref()}.muted = @{muted};
+        }
+    }
+
+    /// Returns the URL of the media, or an empty string if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/src)
+    // https://html.spec.whatwg.org/#dom-media-src
+    fn src( &self ) -> String {
+        js!(
+            return @{self.as_ref()}.src;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the URL of the media and starts loading it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/src)
+    // https://html.spec.whatwg.org/#dom-media-src
+    fn set_src( &self, src: &str ) {
+        js! { @(no_return)
+            @{self.as_ref()}.src = @{src};
+        }
+    }
+}
+
+/// A reference to a JavaScript object which implements the [IMediaElement](../trait.IMediaElement.html)
+/// interface, i.e. an `<audio>` or a `<video>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement)
+// https://html.spec.whatwg.org/#htmlmediaelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLMediaElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement))]
+pub struct MediaElement( Reference );
+
+impl IEventTarget for MediaElement {}
+impl INode for MediaElement {}
+impl IElement for MediaElement {}
+impl IHtmlElement for MediaElement {}
+impl IMediaElement for MediaElement {}
@@ -6,6 +6,9 @@ mod select;
 mod option;
 mod template;
 mod slot;
+mod media;
+mod audio;
+mod video;
 
 pub use self::canvas::CanvasElement;
 pub use self::image::ImageElement;
@@ -15,5 +18,8 @@ pub use self::select::SelectElement;
 pub use self::option::OptionElement;
 pub use self::template::TemplateElement;
 pub use self::slot::{SlotElement, SlotContentKind};
+pub use self::media::{IMediaElement, MediaElement};
+pub use self::audio::AudioElement;
+pub use self::video::VideoElement;
 
 pub use self::select::UnknownValueError;
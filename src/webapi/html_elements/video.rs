@@ -0,0 +1,85 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::node::{INode, Node};
+use webapi::element::{IElement, Element};
+use webapi::html_element::{IHtmlElement, HtmlElement};
+use webapi::html_elements::media::{IMediaElement, MediaElement};
+
+/// The HTML video element is used to play videos, e.g. from a `<video>` element.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+// https://html.spec.whatwg.org/#htmlvideoelement
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "HTMLVideoElement")]
+#[reference(subclass_of(EventTarget, Node, Element, HtmlElement, MediaElement))]
+pub struct VideoElement( Reference );
+
+impl IEventTarget for VideoElement {}
+impl INode for VideoElement {}
+impl IElement for VideoElement {}
+impl IHtmlElement for VideoElement {}
+impl IMediaElement for VideoElement {}
+
+impl VideoElement {
+    /// Returns the intrinsic width of the video in pixels, or `0` if it isn't known yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+    // https://html.spec.whatwg.org/#dom-video-videowidth
+    pub fn video_width( &self ) -> u32 {
+        js!(
+            return @{self}.videoWidth;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the intrinsic height of the video in pixels, or `0` if it isn't known yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/HTMLVideoElement)
+    // https://html.spec.whatwg.org/#dom-video-videoheight
+    pub fn video_height( &self ) -> u32 {
+        js!(
+            return @{self}.videoHeight;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the URL of the image shown until the video starts playing,
+    /// or an empty string if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/video#attr-poster)
+    // https://html.spec.whatwg.org/#dom-video-poster
+    pub fn poster( &self ) -> String {
+        js!(
+            return @{self}.poster;
+        ).try_into().unwrap()
+    }
+
+    /// Sets the URL of the image shown until the video starts playing.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/video#attr-poster)
+    // https://html.spec.whatwg.org/#dom-video-poster
+    pub fn set_poster( &self, poster: &str ) {
+        js! { @(no_return)
+            @{self}.poster = @{poster};
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_video_element() {
+        let video: VideoElement = document().create_element( "video" ).unwrap().try_into().unwrap();
+        assert_eq!( video.video_width(), 0 );
+        assert_eq!( video.video_height(), 0 );
+        assert!( video.paused() );
+
+        video.set_poster( "http://localhost/poster.png" );
+        assert_eq!( video.poster(), "http://localhost/poster.png" );
+
+        video.set_current_time( 0.0 );
+        assert_eq!( video.current_time(), 0.0 );
+    }
+}
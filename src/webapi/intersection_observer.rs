@@ -0,0 +1,174 @@
+use std;
+use webcore::value::Reference;
+use webcore::mutfn::Mut;
+use webcore::try_from::TryInto;
+use webapi::element::{IElement, Element};
+
+/// Provides a way to receive notifications when elements start or stop
+/// intersecting with the viewport.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver)
+// https://w3c.github.io/IntersectionObserver/#intersection-observer-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "IntersectionObserver")]
+pub struct IntersectionObserver( Reference );
+
+impl IntersectionObserver {
+    /// Returns a new [`IntersectionObserverHandle`](struct.IntersectionObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when the intersection
+    /// of an observed element with the viewport changes:
+    ///
+    /// 1. A vector of entries describing the changed intersections.
+    ///
+    /// 2. The `IntersectionObserver`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/IntersectionObserver)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-intersectionobserver
+    pub fn new< F >( callback: F ) -> IntersectionObserverHandle
+        where F: FnMut( Vec< IntersectionObserverEntry >, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{Mut(callback)}; ).try_into().unwrap();
+
+        IntersectionObserverHandle {
+            callback_reference: callback_reference.clone(),
+
+            intersection_observer: js! (
+                return new IntersectionObserver( @{callback_reference} );
+            ).try_into().unwrap(),
+        }
+    }
+
+    /// Starts observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/observe)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-observe
+    pub fn observe< T: IElement >( &self, target: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.observe( @{target.as_ref()} );
+        }
+    }
+
+    /// Stops observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/unobserve)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-unobserve
+    pub fn unobserve< T: IElement >( &self, target: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.unobserve( @{target.as_ref()} );
+        }
+    }
+
+    /// Stops observing all targets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver/disconnect)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.disconnect();
+        }
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`IntersectionObserver::new`](struct.IntersectionObserver.html#method.new) method, and
+/// it can use the same methods as [`IntersectionObserver`](struct.IntersectionObserver.html).
+///
+/// When the `IntersectionObserverHandle` is dropped, the [`disconnect`](#method.disconnect)
+/// method will automatically be called.
+#[ derive( Debug ) ]
+pub struct IntersectionObserverHandle {
+    intersection_observer: IntersectionObserver,
+    callback_reference: Reference,
+}
+
+impl std::ops::Deref for IntersectionObserverHandle {
+    type Target = IntersectionObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.intersection_observer
+    }
+}
+
+impl Drop for IntersectionObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// Describes a change in the intersection of an element observed by an
+/// [`IntersectionObserver`](struct.IntersectionObserver.html) with the viewport.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry)
+// https://w3c.github.io/IntersectionObserver/#intersection-observer-entry
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "IntersectionObserverEntry")]
+pub struct IntersectionObserverEntry( Reference );
+
+impl IntersectionObserverEntry {
+    /// The element whose intersection changed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/target)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-target
+    pub fn target( &self ) -> Element {
+        js!(
+            return @{self}.target;
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if the element intersects with the viewport.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/isIntersecting)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-isintersecting
+    pub fn is_intersecting( &self ) -> bool {
+        js!(
+            return @{self}.isIntersecting;
+        ).try_into().unwrap()
+    }
+
+    /// Returns how much of the element is visible, between `0.0` and `1.0`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/intersectionRatio)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-intersectionratio
+    pub fn intersection_ratio( &self ) -> f64 {
+        js!(
+            return @{self}.intersectionRatio;
+        ).try_into().unwrap()
+    }
+
+    /// The time at which the intersection changed, in milliseconds since the time origin.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserverEntry/time)
+    // https://w3c.github.io/IntersectionObserver/#dom-intersectionobserverentry-time
+    pub fn time( &self ) -> f64 {
+        js!(
+            return @{self}.time;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_observe() {
+        let element = document().create_element( "div" ).unwrap();
+        document().body().unwrap().append_child( &element );
+
+        let observer = IntersectionObserver::new( |_, _| {} );
+        observer.observe( &element );
+        observer.unobserve( &element );
+        drop( observer );
+
+        document().body().unwrap().remove_child( &element ).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+use webcore::value::Value;
+use webapi::error::Error;
+
+/// Parses a JSON string into a [Value](../enum.Value.html) using the browser's native `JSON` object.
+///
+/// Returns a `SyntaxError` wrapped in an [Error](error/struct.Error.html) if the string isn't valid JSON.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-json.parse
+pub fn json_parse( text: &str ) -> Result< Value, Error > {
+    js_try!(
+        return JSON.parse( @{text} );
+    ).unwrap()
+}
+
+/// Serializes a [Value](../enum.Value.html) into a JSON string using the browser's native `JSON` object.
+///
+/// Returns a `TypeError` wrapped in an [Error](error/struct.Error.html) if the value contains
+/// a cycle, a `BigInt`, or can't be represented in JSON at all (like `undefined` or a function).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-json.stringify
+pub fn json_stringify( value: &Value ) -> Result< String, Error > {
+    js_try!(
+        var output = JSON.stringify( @{value} );
+        if( output === undefined ) {
+            throw new TypeError( "the value can't be represented in JSON" );
+        }
+        return output;
+    ).unwrap()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webcore::try_from::TryInto;
+    use webapi::error::IError;
+
+    #[test]
+    fn test_round_trip() {
+        let value = json_parse( r#"{"a":1}"# ).unwrap();
+        let a: i32 = js!( return @{&value}.a; ).try_into().unwrap();
+        assert_eq!( a, 1 );
+        assert_eq!( json_stringify( &value ).unwrap(), r#"{"a":1}"# );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!( json_parse( "{" ).unwrap_err().name(), "SyntaxError" );
+        assert_eq!( json_stringify( &Value::Undefined ).unwrap_err().name(), "TypeError" );
+
+        let cyclic = js!( var object = {}; object.self = object; return object; );
+        assert_eq!( json_stringify( &cyclic ).unwrap_err().name(), "TypeError" );
+    }
+}
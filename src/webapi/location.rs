@@ -1,5 +1,6 @@
 use webcore::value::Reference;
 use webapi::dom_exception::SecurityError;
+use webapi::url::UrlSearchParams;
 
 /// The `Location` interface represents the location (URL) of the object it
 /// is linked to. Changes done on it are reflected on the object it relates
@@ -108,4 +109,31 @@ impl Location {
     pub fn hash( &self ) -> Result< String, SecurityError > {
         js_try!( return @{self}.hash; ).unwrap()
     }
+
+    /// Returns the parsed query string of the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-urlsearchparams
+    pub fn search_params( &self ) -> Result< UrlSearchParams, SecurityError > {
+        js_try!( return new URLSearchParams( @{self}.search ); ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+
+    #[test]
+    fn test_search_params() {
+        let history = window().history();
+        let location = window().location().unwrap();
+        let original = location.href().unwrap();
+
+        history.replace_state( (), "", Some( "?a=1&b=2" ) ).unwrap();
+        let params = location.search_params().unwrap();
+        assert_eq!( params.get( "a" ), Some( "1".to_owned() ) );
+        assert_eq!( params.get( "b" ), Some( "2".to_owned() ) );
+
+        history.replace_state( (), "", Some( &original ) ).unwrap();
+    }
 }
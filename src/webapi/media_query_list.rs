@@ -0,0 +1,204 @@
+#[cfg(feature = "futures-support")]
+use std::pin::Pin;
+#[cfg(feature = "futures-support")]
+use std::task::{Poll, Context};
+
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget, EventListenerHandle};
+use webapi::events::dom::ChangeEvent;
+use webapi::window::window;
+
+#[cfg(feature = "futures-support")]
+use webcore::value::Value;
+#[cfg(feature = "futures-support")]
+use futures_core::stream::Stream;
+#[cfg(feature = "futures-support")]
+use futures_util::StreamExt;
+#[cfg(feature = "futures-support")]
+use futures_channel::mpsc;
+
+/// The `MediaQueryList` object stores information on a media query applied to a document
+/// and handles sending notifications to listeners when the media query state changes.
+///
+/// Use [Window::match_media](struct.Window.html#method.match_media) to create one.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList)
+// https://drafts.csswg.org/cssom-view/#mediaquerylist
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "MediaQueryList")]
+#[reference(subclass_of(EventTarget))]
+pub struct MediaQueryList( Reference );
+
+impl IEventTarget for MediaQueryList {}
+
+impl MediaQueryList {
+    /// Returns `true` if the document currently matches the media query list.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/matches)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-matches
+    pub fn matches( &self ) -> bool {
+        js!(
+            return @{self}.matches;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the serialized media query.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/media)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-media
+    pub fn media( &self ) -> String {
+        js!(
+            return @{self}.media;
+        ).try_into().unwrap()
+    }
+
+    /// Returns a [`Stream`](https://rust-lang-nursery.github.io/futures-api-docs/0.3.0-alpha.13/futures/stream/trait.Stream.html)
+    /// which outputs the new value of [matches](#method.matches) every time
+    /// this media query list changes.
+    ///
+    /// The underlying `change` listener is removed when the stream is dropped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList/onchange)
+    // https://drafts.csswg.org/cssom-view/#dom-mediaquerylist-onchange
+    #[cfg(feature = "futures-support")]
+    pub fn changes( &self ) -> MediaQueryListChanges {
+        MediaQueryListChanges::new( self )
+    }
+}
+
+const DARK_COLOR_SCHEME_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Returns `true` if the user has asked for a dark color scheme, e.g. through
+/// the operating system's dark mode setting.
+///
+/// This is a shorthand for matching the `(prefers-color-scheme: dark)` media query.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme)
+// https://drafts.csswg.org/mediaqueries-5/#prefers-color-scheme
+pub fn prefers_dark_mode() -> bool {
+    window().match_media( DARK_COLOR_SCHEME_QUERY ).matches()
+}
+
+/// Registers a callback which is called with the new value of
+/// [prefers_dark_mode](fn.prefers_dark_mode.html) every time the user's
+/// preferred color scheme changes.
+///
+/// The callback is registered until the returned handle's
+/// [remove](struct.EventListenerHandle.html#method.remove) method is called.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme)
+// https://drafts.csswg.org/mediaqueries-5/#prefers-color-scheme
+pub fn on_color_scheme_change< F: FnMut( bool ) + 'static >( mut callback: F ) -> EventListenerHandle {
+    let list = window().match_media( DARK_COLOR_SCHEME_QUERY );
+    list.clone().add_event_listener( move |_: ChangeEvent| {
+        callback( list.matches() );
+    })
+}
+
+/// The [`Stream`](https://rust-lang-nursery.github.io/futures-api-docs/0.3.0-alpha.13/futures/stream/trait.Stream.html)
+/// which is returned by [`MediaQueryList::changes`](struct.MediaQueryList.html#method.changes).
+#[cfg(feature = "futures-support")]
+#[derive( Debug )]
+pub struct MediaQueryListChanges {
+    receiver: mpsc::UnboundedReceiver< bool >,
+    listener: Value,
+}
+
+#[cfg(feature = "futures-support")]
+impl MediaQueryListChanges {
+    fn new( list: &MediaQueryList ) -> Self {
+        let ( sender, receiver ) = mpsc::unbounded();
+
+        let callback = move |matches: bool| {
+            match sender.unbounded_send( matches ) {
+                Ok( _ ) => {},
+                Err( _ ) => {},
+            };
+        };
+
+        let listener = js!(
+            var list = @{list};
+            var callback = @{callback};
+            var listener = function() {
+                callback( list.matches );
+            };
+
+            list.addEventListener( "change", listener );
+
+            return {
+                list: list,
+                callback: callback,
+                listener: listener
+            };
+        );
+
+        Self {
+            receiver,
+            listener,
+        }
+    }
+}
+
+#[cfg(feature = "futures-support")]
+impl Stream for MediaQueryListChanges {
+    type Item = bool;
+
+    #[inline]
+    fn poll_next( mut self: Pin< &mut Self >, cx: &mut Context ) -> Poll< Option< Self::Item > > {
+        self.receiver.poll_next_unpin( cx )
+    }
+}
+
+#[cfg(feature = "futures-support")]
+impl Drop for MediaQueryListChanges {
+    #[inline]
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            var state = @{&self.listener};
+            state.list.removeEventListener( "change", state.listener );
+            state.callback.drop();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefers_dark_mode() {
+        assert_eq!( prefers_dark_mode(), window().match_media( "(prefers-color-scheme: dark)" ).matches() );
+
+        let handle = on_color_scheme_change( |_| {} );
+        handle.remove();
+    }
+
+    #[test]
+    fn test_match_media() {
+        let list = window().match_media( "(min-width: 0px)" );
+        assert_eq!( list.media(), "(min-width: 0px)" );
+        assert!( list.matches() );
+
+        let list = window().match_media( "not all" );
+        assert!( !list.matches() );
+    }
+
+    #[cfg(feature = "futures-support")]
+    #[test]
+    fn test_changes() {
+        use std::task::{Poll, Context};
+        use futures_util::StreamExt;
+        use futures_util::task::noop_waker_ref;
+
+        let list = window().match_media( "(min-width: 0px)" );
+        let mut changes = list.changes();
+        let mut context = Context::from_waker( noop_waker_ref() );
+
+        assert_eq!( changes.poll_next_unpin( &mut context ), Poll::Pending );
+
+        js!( @(no_return) @{&list}.dispatchEvent( new Event( "change" ) ); );
+        assert_eq!( changes.poll_next_unpin( &mut context ), Poll::Ready( Some( true ) ) );
+        assert_eq!( changes.poll_next_unpin( &mut context ), Poll::Pending );
+    }
+}
@@ -45,6 +45,31 @@ pub mod midi;
 pub mod slotable;
 pub mod shadow_root;
 pub mod form_data;
+pub mod media_query_list;
+pub mod weak_collections;
+pub mod fetch;
+pub mod headers;
+pub mod router;
+pub mod url;
+pub mod resize_observer;
+pub mod intersection_observer;
+pub mod data_view;
+pub mod json;
+pub mod uri;
+pub mod base64;
+pub mod performance;
+pub mod css_style_declaration;
+pub mod streams;
+pub mod navigator;
+pub mod battery;
+pub mod audio;
+pub mod text_codec;
+pub mod crypto;
+pub mod screen;
+pub mod notification;
 
 #[cfg(feature = "futures-support")]
 pub mod timer_future;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+pub mod dialog;
@@ -0,0 +1,206 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::battery::BatteryManager;
+
+/// The `Navigator` interface represents the state and the identity of the user agent.
+///
+/// It can be obtained with [Window::navigator](struct.Window.html#method.navigator).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator)
+// https://html.spec.whatwg.org/#the-navigator-object
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Navigator")]
+pub struct Navigator( Reference );
+
+impl Navigator {
+    /// Returns the user agent string of the browser.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorID/userAgent)
+    // https://html.spec.whatwg.org/#dom-navigator-useragent
+    pub fn user_agent( &self ) -> String {
+        js!(
+            return @{self}.userAgent;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the preferred language of the user, usually the language of the browser UI,
+    /// as a BCP 47 language tag, e.g. `"en-US"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorLanguage/language)
+    // https://html.spec.whatwg.org/#dom-navigator-language
+    pub fn language( &self ) -> Option< String > {
+        js!(
+            return @{self}.language;
+        ).try_into().ok()
+    }
+
+    /// Returns the user's preferred languages, ordered by preference with the most preferred first.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorLanguage/languages)
+    // https://html.spec.whatwg.org/#dom-navigator-languages
+    pub fn languages( &self ) -> Vec< String > {
+        js!(
+            var navigator = @{self};
+            if( navigator.languages ) {
+                return Array.from( navigator.languages );
+            }
+            return navigator.language ? [ navigator.language ] : [];
+        ).try_into().unwrap()
+    }
+
+    /// Returns `false` if the browser is definitely offline, and `true` otherwise.
+    ///
+    /// The [OnlineEvent](event/struct.OnlineEvent.html) and [OfflineEvent](event/struct.OfflineEvent.html)
+    /// are fired on the window when this changes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorOnLine/onLine)
+    // https://html.spec.whatwg.org/#dom-navigator-online
+    pub fn on_line( &self ) -> bool {
+        js!(
+            return @{self}.onLine;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the number of logical processors available to run threads on the user's computer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/NavigatorConcurrentHardware/hardwareConcurrency)
+    // https://html.spec.whatwg.org/#dom-navigator-hardwareconcurrency
+    pub fn hardware_concurrency( &self ) -> u32 {
+        js!(
+            return @{self}.hardwareConcurrency || 1;
+        ).try_into().unwrap()
+    }
+
+    /// Vibrates the device's vibration hardware, if there is any, with the given pattern.
+    ///
+    /// The pattern alternates between vibration and pause durations in milliseconds,
+    /// starting with a vibration; a single element vibrates once. An empty pattern
+    /// cancels any ongoing vibration.
+    ///
+    /// Returns `false` if the pattern is invalid or the browser doesn't support vibration.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/vibrate)
+    // https://w3c.github.io/vibration/#vibrate-method
+    pub fn vibrate( &self, pattern: &[u32] ) -> bool {
+        js!(
+            var navigator = @{self};
+            if( !navigator.vibrate ) {
+                return false;
+            }
+            return navigator.vibrate( @{pattern} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns a future which resolves to the [BatteryManager](struct.BatteryManager.html)
+    /// with information about the system's battery.
+    ///
+    /// The future resolves to an `Err` if the browser doesn't support the Battery Status API.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getBattery)
+    // https://w3c.github.io/battery/#the-getbattery-method
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn get_battery( &self ) -> PromiseFuture< BatteryManager > {
+        js!(
+            var navigator = @{self};
+            if( !navigator.getBattery ) {
+                return Promise.reject( new Error( "the Battery Status API is not supported" ) );
+            }
+            return navigator.getBattery();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the [Clipboard](struct.Clipboard.html) object which gives access to the system clipboard.
+    ///
+    /// Returns `None` if the page isn't running in a secure context or the browser doesn't
+    /// support the asynchronous Clipboard API.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/clipboard)
+    // https://w3c.github.io/clipboard-apis/#dom-navigator-clipboard
+    pub fn clipboard( &self ) -> Option< Clipboard > {
+        js!(
+            return @{self}.clipboard;
+        ).try_into().ok()
+    }
+}
+
+/// The `Clipboard` interface provides asynchronous read and write access to the system clipboard.
+///
+/// Access may require the user's permission; if it's denied the returned futures resolve to an `Err`.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard)
+// https://w3c.github.io/clipboard-apis/#clipboard-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Clipboard")]
+pub struct Clipboard( Reference );
+
+impl Clipboard {
+    /// Writes the given text to the system clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/writeText)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-writetext
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn write_text( &self, text: &str ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.writeText( @{text} );
+        ).try_into().unwrap()
+    }
+
+    /// Reads the text currently in the system clipboard.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/readText)
+    // https://w3c.github.io/clipboard-apis/#dom-clipboard-readtext
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn read_text( &self ) -> PromiseFuture< String > {
+        js!(
+            return @{self}.readText();
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
+
+    #[test]
+    fn test_navigator() {
+        let navigator = window().navigator();
+        assert!( !navigator.user_agent().is_empty() );
+        assert!( navigator.hardware_concurrency() >= 1 );
+
+        // Vibration can't be observed here, but the call must not fail.
+        navigator.vibrate( &[ 200 ] );
+        navigator.vibrate( &[] );
+
+        let languages = navigator.languages();
+        if let Some( language ) = navigator.language() {
+            assert!( languages.contains( &language ) );
+        }
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_get_battery< F: FnOnce() >( done: F ) {
+        use webcore::promise_future::spawn_local;
+        use futures_util::FutureExt;
+        use webcore::try_from::TryInto;
+
+        let navigator = window().navigator();
+        let supported: bool = js!( return !!@{&navigator}.getBattery; ).try_into().unwrap();
+        if !supported {
+            done();
+            return;
+        }
+
+        spawn_local( navigator.get_battery().map( move |result| {
+            let level = result.unwrap().level();
+            assert!( level >= 0.0 && level <= 1.0 );
+            done();
+        }));
+    }
+}
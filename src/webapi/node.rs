@@ -710,6 +710,20 @@ mod tests {
         assert_eq!(node.owner_document().unwrap().as_ref(), document().as_ref());
     }
 
+    #[test]
+    fn test_owner_document_of_appended_node() {
+        let body = document().body().unwrap();
+        let node = div();
+        body.append_child(&node);
+        assert_eq!(node.owner_document().unwrap(), document());
+        body.remove_child(&node).unwrap();
+    }
+
+    #[test]
+    fn test_owner_document_of_document() {
+        assert!(document().owner_document().is_none());
+    }
+
     #[test]
     fn test_parent_element() {
         let node = div();
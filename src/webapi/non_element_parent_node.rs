@@ -1,3 +1,4 @@
+use webcore::value::Reference;
 use webcore::reference_type::ReferenceType;
 use webapi::element::Element;
 
@@ -18,4 +19,40 @@ pub trait INonElementParentNode: ReferenceType {
             js!( return @{self.as_ref()}.getElementById( @{id} ); ).into_reference_unchecked()
         }
     }
+
+    /// Same as [get_element_by_id](#method.get_element_by_id), except the element
+    /// is also downcast to `T`.
+    ///
+    /// Returns `None` if there is no element with the given ID or if it isn't a `T`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementById)
+    // https://dom.spec.whatwg.org/#ref-for-dom-nonelementparentnode-getelementbyid
+    fn get_element_by_id_as< T: ReferenceType >( &self, id: &str ) -> Option< T > {
+        Reference::from( self.get_element_by_id( id )? ).downcast()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::node::INode;
+    use webapi::element::IElement;
+    use webapi::html_elements::{InputElement, ImageElement};
+
+    #[test]
+    fn test_get_element_by_id_as() {
+        let body = document().body().unwrap();
+        let input = document().create_element( "input" ).unwrap();
+        input.set_attribute( "id", "test-get-element-by-id-as" ).unwrap();
+        body.append_child( &input );
+
+        let found: InputElement = document().get_element_by_id_as( "test-get-element-by-id-as" ).unwrap();
+        assert_eq!( found.as_ref(), input.as_ref() );
+
+        assert!( document().get_element_by_id_as::< ImageElement >( "test-get-element-by-id-as" ).is_none() );
+        assert!( document().get_element_by_id_as::< InputElement >( "test-get-element-by-id-as-missing" ).is_none() );
+
+        body.remove_child( &input ).unwrap();
+    }
 }
@@ -0,0 +1,225 @@
+use webcore::value::{Reference, Value, ConversionError};
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::event::{IEvent, Event};
+use webapi::error::TypeError;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// Whether the user has allowed the page to show notifications.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/permission)
+// https://notifications.spec.whatwg.org/#enumdef-notificationpermission
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NotificationPermission {
+    /// The user has allowed notifications.
+    Granted,
+    /// The user has refused notifications.
+    Denied,
+    /// The user hasn't made a choice yet, which is treated like `Denied`.
+    Default,
+}
+
+impl TryFrom< Value > for NotificationPermission {
+    type Error = ConversionError;
+
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        let value: String = value.try_into()?;
+        match value.as_ref() {
+            "granted" => Ok( NotificationPermission::Granted ),
+            "denied" => Ok( NotificationPermission::Denied ),
+            "default" => Ok( NotificationPermission::Default ),
+            s => Err( ConversionError::Custom( format!( "invalid notification permission \"{}\"", s ) ) ),
+        }
+    }
+}
+
+/// Options which can be passed to [Notification::new](struct.Notification.html#method.new).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/Notification#Parameters)
+// https://notifications.spec.whatwg.org/#dictdef-notificationoptions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NotificationOptions {
+    /// The text shown below the notification's title.
+    pub body: Option< String >,
+    /// The URL of an image shown next to the notification.
+    pub icon: Option< String >,
+    /// An identifier for the notification; a new notification replaces
+    /// any existing one with the same tag.
+    pub tag: Option< String >,
+}
+
+/// The `Notification` interface is used to display desktop notifications to the user.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification)
+// https://notifications.spec.whatwg.org/#notification
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Notification")]
+#[reference(subclass_of(EventTarget))]
+pub struct Notification( Reference );
+
+impl IEventTarget for Notification {}
+
+impl Notification {
+    /// Shows a new notification with the given title.
+    ///
+    /// The notification is only shown if the user has granted the permission
+    /// to do so; otherwise an `error` event is dispatched on it.
+    ///
+    /// Fails with a `TypeError` if the browser doesn't allow creating
+    /// notifications outside of a service worker.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/Notification)
+    // https://notifications.spec.whatwg.org/#dom-notification-notification
+    pub fn new( title: &str, options: NotificationOptions ) -> Result< Self, TypeError > {
+        js_try!(
+            var options = {};
+            var body = @{options.body};
+            var icon = @{options.icon};
+            var tag = @{options.tag};
+            if( body !== null ) {
+                options.body = body;
+            }
+            if( icon !== null ) {
+                options.icon = icon;
+            }
+            if( tag !== null ) {
+                options.tag = tag;
+            }
+
+            return new Notification( @{title}, options );
+        ).unwrap()
+    }
+
+    /// Returns whether the user has allowed the page to show notifications.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/permission)
+    // https://notifications.spec.whatwg.org/#dom-notification-permission
+    pub fn permission() -> NotificationPermission {
+        js!(
+            return Notification.permission;
+        ).try_into().unwrap()
+    }
+
+    /// Asks the user whether the page may show notifications.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/requestPermission)
+    // https://notifications.spec.whatwg.org/#dom-notification-requestpermission
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn request_permission() -> PromiseFuture< NotificationPermission > {
+        js!(
+            // Older browsers only support the callback form and return `undefined`.
+            return new Promise( function( resolve ) {
+                var promise = Notification.requestPermission( resolve );
+                if( promise ) {
+                    promise.then( resolve );
+                }
+            });
+        ).try_into().unwrap()
+    }
+
+    /// Returns the title of the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/title)
+    // https://notifications.spec.whatwg.org/#dom-notification-title
+    pub fn title( &self ) -> String {
+        js!(
+            return @{self}.title;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the body text of the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/body)
+    // https://notifications.spec.whatwg.org/#dom-notification-body
+    pub fn body( &self ) -> String {
+        js!(
+            return @{self}.body;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the tag of the notification, or an empty string if it has none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/tag)
+    // https://notifications.spec.whatwg.org/#dom-notification-tag
+    pub fn tag( &self ) -> String {
+        js!(
+            return @{self}.tag;
+        ).try_into().unwrap()
+    }
+
+    /// Closes the notification.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/close)
+    // https://notifications.spec.whatwg.org/#dom-notification-close
+    pub fn close( &self ) {
+        js! { @(no_return)
+            @{self}.close();
+        }
+    }
+}
+
+/// The `NotificationClickEvent` is fired on a [Notification](../struct.Notification.html)
+/// when the user clicks on it.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Notification/onclick)
+// https://notifications.spec.whatwg.org/#activating-a-notification
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Event")]
+#[reference(event = "click")]
+#[reference(subclass_of(Event))]
+pub struct NotificationClickEvent( Reference );
+
+impl IEvent for NotificationClickEvent {}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_conversion() {
+        let granted: NotificationPermission = Value::from( "granted" ).try_into().unwrap();
+        assert_eq!( granted, NotificationPermission::Granted );
+        let denied: NotificationPermission = Value::from( "denied" ).try_into().unwrap();
+        assert_eq!( denied, NotificationPermission::Denied );
+        let default: NotificationPermission = Value::from( "default" ).try_into().unwrap();
+        assert_eq!( default, NotificationPermission::Default );
+
+        let invalid: Result< NotificationPermission, _ > = Value::from( "maybe" ).try_into();
+        assert!( invalid.is_err() );
+    }
+
+    #[test]
+    fn test_new_notification() {
+        let options = NotificationOptions {
+            body: Some( "Body".to_owned() ),
+            icon: None,
+            tag: Some( "stdweb-test".to_owned() ),
+        };
+
+        // Some browsers only allow creating notifications from a service worker,
+        // in which case construction fails with a `TypeError`.
+        if let Ok( notification ) = Notification::new( "Title", options ) {
+            assert_eq!( notification.title(), "Title" );
+            assert_eq!( notification.body(), "Body" );
+            assert_eq!( notification.tag(), "stdweb-test" );
+            notification.close();
+        }
+
+        if let Ok( notification ) = Notification::new( "Untagged", NotificationOptions::default() ) {
+            assert_eq!( notification.title(), "Untagged" );
+            assert_eq!( notification.body(), "" );
+            assert_eq!( notification.tag(), "" );
+            notification.close();
+        }
+    }
+
+    #[test]
+    fn test_notification_click_event() {
+        let event: NotificationClickEvent = js!(
+            return new Event( "click" );
+        ).try_into().unwrap();
+        assert_eq!( event.event_type(), "click" );
+    }
+}
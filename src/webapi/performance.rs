@@ -0,0 +1,230 @@
+use std;
+use webcore::value::Reference;
+use webcore::mutfn::Mut;
+use webcore::try_from::TryInto;
+
+/// The `Performance` interface provides access to high resolution timing
+/// and performance-related information for the current page.
+///
+/// It can be obtained with [Window::performance](struct.Window.html#method.performance).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance)
+// https://w3c.github.io/hr-time/#the-performance-attribute
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Performance")]
+pub struct Performance( Reference );
+
+impl Performance {
+    /// Returns a high resolution timestamp in milliseconds, measured
+    /// relative to the [time origin](#method.time_origin).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/now)
+    // https://w3c.github.io/hr-time/#dom-performance-now
+    pub fn now( &self ) -> f64 {
+        js!(
+            return @{self}.now();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the time at which the current page started loading, as a high
+    /// resolution timestamp in milliseconds since the Unix epoch.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Performance/timeOrigin)
+    // https://w3c.github.io/hr-time/#dom-performance-timeorigin
+    pub fn time_origin( &self ) -> f64 {
+        js!(
+            return @{self}.timeOrigin;
+        ).try_into().unwrap()
+    }
+}
+
+/// Provides a way to receive notifications about new performance entries, like
+/// user timing marks and measures, paint timings or long tasks.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver)
+// https://w3c.github.io/performance-timeline/#the-performanceobserver-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceObserver")]
+pub struct PerformanceObserver( Reference );
+
+impl PerformanceObserver {
+    /// Returns a new [`PerformanceObserverHandle`](struct.PerformanceObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when new entries of the observed types are recorded:
+    ///
+    /// 1. A vector of the new performance entries.
+    ///
+    /// 2. The `PerformanceObserver`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/PerformanceObserver)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-constructor
+    pub fn new< F >( callback: F ) -> PerformanceObserverHandle
+        where F: FnMut( Vec< PerformanceEntry >, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{Mut(callback)}; ).try_into().unwrap();
+
+        PerformanceObserverHandle {
+            callback_reference: callback_reference.clone(),
+
+            performance_observer: js! (
+                var callback = @{callback_reference};
+                return new PerformanceObserver( function( list, observer ) {
+                    callback( list.getEntries(), observer );
+                });
+            ).try_into().unwrap(),
+        }
+    }
+
+    /// Starts observing new performance entries of the given types, like
+    /// `"mark"`, `"measure"`, `"paint"` or `"longtask"`.
+    ///
+    /// Entry types which aren't supported by the browser are ignored.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/observe)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-observe
+    pub fn observe( &self, entry_types: &[ &str ] ) {
+        js! { @(no_return)
+            @{self.as_ref()}.observe( { entryTypes: @{entry_types} } );
+        }
+    }
+
+    /// Stops observing, and discards any entries which weren't passed to the callback yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/disconnect)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.disconnect();
+        }
+    }
+
+    /// Returns all of the entries which were recorded but not yet passed to the callback,
+    /// and removes them from the queue.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceObserver/takeRecords)
+    // https://w3c.github.io/performance-timeline/#dom-performanceobserver-takerecords
+    pub fn take_records( &self ) -> Vec< PerformanceEntry > {
+        js!(
+            return @{self.as_ref()}.takeRecords();
+        ).try_into().unwrap()
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`PerformanceObserver::new`](struct.PerformanceObserver.html#method.new) method, and
+/// it can use the same methods as [`PerformanceObserver`](struct.PerformanceObserver.html).
+///
+/// When the `PerformanceObserverHandle` is dropped, the [`disconnect`](#method.disconnect)
+/// method will automatically be called.
+#[ derive( Debug ) ]
+pub struct PerformanceObserverHandle {
+    performance_observer: PerformanceObserver,
+    callback_reference: Reference,
+}
+
+impl std::ops::Deref for PerformanceObserverHandle {
+    type Target = PerformanceObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.performance_observer
+    }
+}
+
+impl Drop for PerformanceObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// A single performance metric, like a user timing mark or a paint timing.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry)
+// https://w3c.github.io/performance-timeline/#the-performanceentry-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "PerformanceEntry")]
+pub struct PerformanceEntry( Reference );
+
+impl PerformanceEntry {
+    /// The name of the entry, e.g. the name passed to `performance.mark`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/name)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-name
+    pub fn name( &self ) -> String {
+        js!(
+            return @{self}.name;
+        ).try_into().unwrap()
+    }
+
+    /// The type of the entry, e.g. `"mark"`, `"measure"` or `"paint"`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/entryType)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-entrytype
+    pub fn entry_type( &self ) -> String {
+        js!(
+            return @{self}.entryType;
+        ).try_into().unwrap()
+    }
+
+    /// The time at which the entry started, in milliseconds since the page started loading.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/startTime)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-starttime
+    pub fn start_time( &self ) -> f64 {
+        js!(
+            return @{self}.startTime;
+        ).try_into().unwrap()
+    }
+
+    /// The duration of the entry in milliseconds; zero for entries which represent a single point in time.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEntry/duration)
+    // https://w3c.github.io/performance-timeline/#dom-performanceentry-duration
+    pub fn duration( &self ) -> f64 {
+        js!(
+            return @{self}.duration;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::window::window;
+
+    #[test]
+    fn test_now() {
+        let performance = window().performance();
+        let first = performance.now();
+        let second = performance.now();
+        assert!( first >= 0.0 );
+        assert!( second >= first );
+        assert!( performance.time_origin() > 0.0 );
+    }
+
+    #[test]
+    fn test_observe_measure() {
+        let observer = PerformanceObserver::new( |_, _| {} );
+        observer.observe( &[ "measure" ] );
+
+        js! { @(no_return)
+            performance.mark( "stdweb-start" );
+            performance.mark( "stdweb-end" );
+            performance.measure( "stdweb-measure", "stdweb-start", "stdweb-end" );
+        }
+
+        let entries = observer.take_records();
+        assert_eq!( entries.len(), 1 );
+        assert_eq!( entries[ 0 ].name(), "stdweb-measure" );
+        assert_eq!( entries[ 0 ].entry_type(), "measure" );
+        assert!( entries[ 0 ].start_time() >= 0.0 );
+        assert!( entries[ 0 ].duration() >= 0.0 );
+
+        assert!( observer.take_records().is_empty() );
+    }
+}
@@ -1,8 +1,10 @@
 use webcore::value::{Reference, ConversionError};
+use webcore::reference_type::ReferenceType;
 use webcore::try_from::{TryFrom, TryInto};
 use webcore::value::{Undefined, Value};
-use webapi::html_elements::{CanvasElement, ImageElement};
+use webapi::html_elements::{CanvasElement, ImageElement, VideoElement};
 use webapi::html_element::IHtmlElement;
+use webapi::typed_array::TypedArray;
 use webapi::dom_exception::{SyntaxError, IndexSizeError, InvalidStateError, SecurityError, NotSupportedError};
 use webapi::error::TypeError;
 
@@ -14,6 +16,17 @@ pub trait RenderingContext {
     fn from_canvas(canvas: &CanvasElement) -> Result<Self, Self::Error> where Self: Sized;
 }
 
+/// A trait implemented by the types which can be drawn onto a canvas,
+/// e.g. with [CanvasRenderingContext2d::draw_image](struct.CanvasRenderingContext2d.html#method.draw_image).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasImageSource)
+// https://html.spec.whatwg.org/#canvasimagesource
+pub trait CanvasImageSource: ReferenceType {}
+
+impl CanvasImageSource for ImageElement {}
+impl CanvasImageSource for CanvasElement {}
+impl CanvasImageSource for VideoElement {}
+
 /// Used for drawing rectangles, text, images and other objects onto the canvas element.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D)
@@ -131,6 +144,20 @@ pub enum FillRule {
     EvenOdd
 }
 
+/// The quality of image smoothing used when scaling images.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/imageSmoothingQuality)
+// https://html.spec.whatwg.org/#imagesmoothingquality
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageSmoothingQuality {
+    /// Low quality (default)
+    Low,
+    /// Medium quality
+    Medium,
+    /// High quality
+    High
+}
+
 /// Certain style functions can return multiple types
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CanvasStyle {
@@ -225,6 +252,18 @@ pub enum TextBaseline {
     Bottom
 }
 
+/// The color space of an [ImageData](struct.ImageData.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageData/colorSpace)
+// https://html.spec.whatwg.org/#predefinedcolorspace
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PredefinedColorSpace {
+    /// The sRGB color space (default)
+    Srgb,
+    /// The Display P3 color space
+    DisplayP3
+}
+
 error_enum_boilerplate! {
     /// A enum of the exceptions that CanvasGradient.add_color_stop() may throw
     // https://html.spec.whatwg.org/multipage/canvas.html#dom-canvasgradient-addcolorstop
@@ -257,6 +296,15 @@ error_enum_boilerplate! {
     SecurityError
 }
 
+error_enum_boilerplate! {
+    /// A enum of the exceptions that CanvasRenderingContext2D.ellipse() may throw
+    EllipseError,
+    /// An IndexSizeError if either of the radii is negative
+    IndexSizeError,
+    /// A NotSupportedError if the browser doesn't implement ellipses
+    NotSupportedError
+}
+
 impl TryFrom<Value> for CanvasStyle {
     type Error = ConversionError;
 
@@ -309,19 +357,27 @@ impl CanvasGradient {
 
 impl ImageData {
 
-    /*
-    /// Returns a Uint8ClampedArray representing a one-dimensional array containing the data in the RGBA order,
+    /// Returns a one-dimensional array containing the data in the RGBA order,
     /// with integer values between 0 and 255 (included).
     ///
+    /// The returned array is a `Uint8Array` view of the same memory as the
+    /// underlying `Uint8ClampedArray`.
+    ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageData/data)
     // https://html.spec.whatwg.org/#2dcontext:dom-imagedata-data
-    // TODO: Return Uint8ClampedArray reference PR 96: https://github.com/koute/stdweb/pull/96
-
     pub fn get_data(&self) -> TypedArray<u8> {
         js! (
-            return @{&self.0}.data;
+            var data = @{&self.0}.data;
+            return new Uint8Array(data.buffer, data.byteOffset, data.length);
         ).try_into().unwrap()
-    }*/
+    }
+
+    /// Copies the RGBA data of this image data into a new `Vec`.
+    ///
+    /// Mutating the returned `Vec` does **not** write back into the image data.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.get_data().to_vec()
+    }
 
     /// Returns the number of rows in the image data object.
     ///
@@ -342,6 +398,23 @@ impl ImageData {
             return @{&self.0}.width;
         ).try_into().unwrap()
     }
+
+    /// Returns the color space of the image data.
+    /// Browsers which don't support color spaces always use `PredefinedColorSpace::Srgb`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ImageData/colorSpace)
+    // https://html.spec.whatwg.org/#dom-imagedata-colorspace
+    pub fn color_space(&self) -> PredefinedColorSpace {
+        let color_space_str: String = js! (
+            var color_space = @{&self.0}.colorSpace;
+            return color_space === undefined ? "srgb" : color_space;
+        ).try_into().unwrap();
+        match color_space_str.as_ref() {
+            "srgb" => PredefinedColorSpace::Srgb,
+            "display-p3" => PredefinedColorSpace::DisplayP3,
+            _ => panic!("Unexpected colorSpace value: {:?}", color_space_str),
+        }
+    }
 }
 
 impl CanvasRenderingContext2d {
@@ -530,6 +603,59 @@ impl CanvasRenderingContext2d {
         }
     }
 
+    /// Determines whether scaled images are smoothed. By default this property is set to true.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/imageSmoothingEnabled)
+    // https://html.spec.whatwg.org/#dom-context-2d-imagesmoothingenabled
+    pub fn get_image_smoothing_enabled(&self) -> bool {
+        js! (
+            return @{&self.0}.imageSmoothingEnabled;
+        ).try_into().unwrap()
+    }
+
+    /// Sets whether scaled images are smoothed. Disable it to keep scaled pixel art crisp.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/imageSmoothingEnabled)
+    // https://html.spec.whatwg.org/#dom-context-2d-imagesmoothingenabled
+    pub fn set_image_smoothing_enabled(&self, enabled: bool) {
+        js! { @(no_return)
+            @{&self.0}.imageSmoothingEnabled = @{enabled};
+        }
+    }
+
+    /// Determines the quality of image smoothing. By default this property is set to low.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/imageSmoothingQuality)
+    // https://html.spec.whatwg.org/#dom-context-2d-imagesmoothingquality
+    pub fn get_image_smoothing_quality(&self) -> ImageSmoothingQuality {
+        let quality_str: String = js! (
+            var quality = @{&self.0}.imageSmoothingQuality;
+            return quality === undefined ? "low" : quality;
+        ).try_into().unwrap();
+
+        match quality_str.as_ref() {
+            "low" => ImageSmoothingQuality::Low,
+            "medium" => ImageSmoothingQuality::Medium,
+            "high" => ImageSmoothingQuality::High,
+            _ => panic!("Unexpected imageSmoothingQuality value: {:?}", quality_str),
+        }
+    }
+
+    /// Sets the quality of image smoothing. This only has an effect if image smoothing is enabled.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/imageSmoothingQuality)
+    // https://html.spec.whatwg.org/#dom-context-2d-imagesmoothingquality
+    pub fn set_image_smoothing_quality(&self, quality: ImageSmoothingQuality) {
+        let quality_string = match quality {
+            ImageSmoothingQuality::Low => "low",
+            ImageSmoothingQuality::Medium => "medium",
+            ImageSmoothingQuality::High => "high",
+        };
+        js! { @(no_return)
+            @{&self.0}.imageSmoothingQuality = @{quality_string};
+        }
+    }
+
     /// Determines how the end points of every line are drawn.
     /// There are three possible values for this property and those are: butt, round and square.
     /// By default this property is set to butt.
@@ -974,6 +1100,21 @@ impl CanvasRenderingContext2d {
         ).unwrap()
     }
 
+    /// Creates a new, blank ImageData object with the specified dimensions in the given color space.
+    /// All of the pixels in the new object are transparent black.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/createImageData)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-createimagedata
+    pub fn create_image_data_with_color_space(&self, width: f64, height: f64, color_space: PredefinedColorSpace) -> Result<ImageData, IndexSizeError> {
+        let color_space = match color_space {
+            PredefinedColorSpace::Srgb => "srgb",
+            PredefinedColorSpace::DisplayP3 => "display-p3",
+        };
+        js_try! (
+            return @{&self.0}.createImageData(@{width}, @{height}, { colorSpace: @{color_space} });
+        ).unwrap()
+    }
+
     /// Creates a new, blank ImageData object with the specified dimensions.
     /// All of the pixels in the new object are transparent black.
     ///
@@ -1039,9 +1180,9 @@ impl CanvasRenderingContext2d {
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image(&self, image: ImageElement, dx: f64, dy: f64) -> Result<(), DrawImageError> {
+    pub fn draw_image<T: CanvasImageSource>(&self, image: T, dx: f64, dy: f64) -> Result<(), DrawImageError> {
         js_try! (@(no_return)
-            @{&self.0}.drawImage(@{image}, @{dx}, @{dy});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{dx}, @{dy});
         ).unwrap()
     }
 
@@ -1049,9 +1190,9 @@ impl CanvasRenderingContext2d {
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image_d(&self, image: ImageElement, dx: f64, dy: f64, d_width: f64, d_height: f64) -> Result<(), DrawImageError> {
+    pub fn draw_image_d<T: CanvasImageSource>(&self, image: T, dx: f64, dy: f64, d_width: f64, d_height: f64) -> Result<(), DrawImageError> {
         js_try! (@(no_return)
-            @{&self.0}.drawImage(@{image}, @{dx}, @{dy}, @{d_width}, @{d_height});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{dx}, @{dy}, @{d_width}, @{d_height});
         ).unwrap()
     }
 
@@ -1059,12 +1200,30 @@ impl CanvasRenderingContext2d {
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/drawImage)
     // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-drawimage
-    pub fn draw_image_s(&self, image: ImageElement,
+    pub fn draw_image_s<T: CanvasImageSource>(&self, image: T,
                         sx: f64, sy: f64, s_width: f64, s_height: f64,
                         dx: f64, dy: f64, d_width: f64, d_height: f64
                     ) -> Result<(), DrawImageError> {
         js_try!(@(no_return)
-            @{&self.0}.drawImage(@{image}, @{sx}, @{sy}, @{s_width}, @{s_height}, @{dx}, @{dy}, @{d_width}, @{d_height});
+            @{&self.0}.drawImage(@{image.as_ref()}, @{sx}, @{sy}, @{s_width}, @{s_height}, @{dx}, @{dy}, @{d_width}, @{d_height});
+        ).unwrap()
+    }
+
+    /// Adds an elliptical arc to the path which is centered at (x, y) position with the radii radiusX and radiusY
+    /// starting at startAngle and ending at endAngle going in the given direction by anticlockwise.
+    ///
+    /// Returns an `IndexSizeError` if either `radius_x` or `radius_y` is negative,
+    /// or a `NotSupportedError` if the browser doesn't implement ellipses.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/ellipse)
+    // https://html.spec.whatwg.org/#2dcontext:dom-context-2d-ellipse
+    pub fn ellipse(&self, x: f64, y: f64, radius_x: f64, radius_y: f64, rotation: f64, start_angle: f64, end_angle: f64, anticlockwise: bool) -> Result<(), EllipseError> {
+        js_try! ( @(no_return)
+            var ctx = @{&self.0};
+            if (typeof ctx.ellipse !== "function") {
+                throw new DOMException("CanvasRenderingContext2D.ellipse is not supported", "NotSupportedError");
+            }
+            ctx.ellipse(@{x}, @{y}, @{radius_x}, @{radius_y}, @{rotation}, @{start_angle}, @{end_angle}, @{anticlockwise});
         ).unwrap()
     }
 
@@ -1125,6 +1284,34 @@ impl CanvasRenderingContext2d {
         ).unwrap()
     }
 
+    /// Returns the current transformation matrix as `[a, b, c, d, e, f]`, in the same order
+    /// as the arguments of [set_transform](#method.set_transform).
+    ///
+    /// In browsers without `getTransform()` the matrix is read from the older
+    /// `currentTransform` or `mozCurrentTransform` properties instead. If neither is available
+    /// `None` is returned.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/getTransform)
+    // https://html.spec.whatwg.org/#dom-context-2d-gettransform
+    pub fn get_transform(&self) -> Option<[f64; 6]> {
+        let matrix: Option<Vec<f64>> = js! (
+            var ctx = @{&self.0};
+            var m;
+            if (typeof ctx.getTransform === "function") {
+                m = ctx.getTransform();
+            } else if (ctx.currentTransform) {
+                m = ctx.currentTransform;
+            } else if (ctx.mozCurrentTransform) {
+                return ctx.mozCurrentTransform.slice(0, 6);
+            } else {
+                return null;
+            }
+            return [m.a, m.b, m.c, m.d, m.e, m.f];
+        ).try_into().unwrap();
+
+        matrix.map(|matrix| [matrix[0], matrix[1], matrix[2], matrix[3], matrix[4], matrix[5]])
+    }
+
     /// Gets the current line dash pattern.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/getLineDash)
@@ -1246,6 +1433,22 @@ impl CanvasRenderingContext2d {
         }
     }
 
+    /// Resets the current transformation to the identity matrix. This is the same as calling
+    /// `set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/CanvasRenderingContext2D/resetTransform)
+    // https://html.spec.whatwg.org/#dom-context-2d-resettransform
+    pub fn reset_transform(&self) {
+        js! { @(no_return)
+            var ctx = @{&self.0};
+            if (typeof ctx.resetTransform === "function") {
+                ctx.resetTransform();
+            } else {
+                ctx.setTransform(1, 0, 0, 1, 0, 0);
+            }
+        }
+    }
+
     /// Restores the most recently saved canvas state by popping the top entry in the drawing state stack.
     /// If there is no saved state, this method does nothing.
     ///
@@ -1432,4 +1635,113 @@ mod test {
         let res: Result<CanvasGradient, IndexSizeError> = canvas.create_radial_gradient(100 as f64, 100 as f64, -1 as f64, 100 as f64, 100 as f64, 0 as f64);
         assert!(res.is_err());
     }
+
+    #[test]
+    fn test_ellipse() {
+        let canvas = new_canvas();
+        canvas.begin_path();
+        assert!(canvas.ellipse(50.0, 50.0, 40.0, 20.0, 0.0, 0.0, 2.0 * ::std::f64::consts::PI, false).is_ok());
+        canvas.fill(FillRule::NonZero);
+        assert!(canvas.is_point_in_path(50.0, 50.0, FillRule::NonZero));
+        assert!(!canvas.is_point_in_path(50.0, 5.0, FillRule::NonZero));
+
+        match canvas.ellipse(50.0, 50.0, -1.0, 20.0, 0.0, 0.0, 2.0 * ::std::f64::consts::PI, false) {
+            Err(EllipseError::IndexSizeError(_)) => {},
+            result => panic!("unexpected result: {:?}", result)
+        }
+    }
+
+    #[test]
+    fn test_image_data_round_trip() {
+        let canvas = new_canvas();
+        canvas.set_fill_style_color("rgb(255,0,0)");
+        canvas.fill_rect(0.0, 0.0, 2.0, 2.0);
+
+        let image_data = canvas.get_image_data(0.0, 0.0, 2.0, 2.0).unwrap();
+        assert_eq!(image_data.get_data().len(), 16);
+        assert_eq!(&image_data.to_vec()[0..4], &[255, 0, 0, 255]);
+
+        let other = new_canvas();
+        other.put_image_data(image_data, 0.0, 0.0).unwrap();
+        let pixels = other.get_image_data(0.0, 0.0, 2.0, 2.0).unwrap().to_vec();
+        assert_eq!(pixels, [255, 0, 0, 255].repeat(4));
+    }
+
+    #[test]
+    fn test_get_and_reset_transform() {
+        let canvas = new_canvas();
+        assert_eq!(canvas.get_transform(), Some([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]));
+
+        canvas.set_transform(2.0, 0.0, 0.0, 3.0, 10.0, 20.0);
+        assert_eq!(canvas.get_transform(), Some([2.0, 0.0, 0.0, 3.0, 10.0, 20.0]));
+
+        canvas.reset_transform();
+        assert_eq!(canvas.get_transform(), Some([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_get_transform_unsupported() {
+        let canvas = new_canvas();
+        js! { @(no_return)
+            var ctx = @{&canvas.0};
+            Object.defineProperty(ctx, "getTransform", { value: undefined });
+            Object.defineProperty(ctx, "currentTransform", { value: undefined });
+            Object.defineProperty(ctx, "mozCurrentTransform", { value: undefined });
+        }
+        assert_eq!(canvas.get_transform(), None);
+    }
+
+    #[test]
+    fn test_image_smoothing() {
+        let canvas = new_canvas();
+        assert!(canvas.get_image_smoothing_enabled());
+
+        canvas.set_image_smoothing_enabled(false);
+        assert!(!canvas.get_image_smoothing_enabled());
+
+        canvas.set_image_smoothing_quality(ImageSmoothingQuality::High);
+        assert_eq!(canvas.get_image_smoothing_quality(), ImageSmoothingQuality::High);
+    }
+
+    #[test]
+    fn test_draw_canvas_onto_canvas() {
+        let source = new_canvas();
+        source.set_fill_style_color("rgb(0,255,0)");
+        source.fill_rect(0.0, 0.0, 4.0, 4.0);
+
+        let target = new_canvas();
+        target.draw_image(source.get_canvas(), 0.0, 0.0).unwrap();
+        target.draw_image_d(source.get_canvas(), 10.0, 0.0, 8.0, 8.0).unwrap();
+        target.draw_image_s(source.get_canvas(), 0.0, 0.0, 4.0, 4.0, 20.0, 0.0, 4.0, 4.0).unwrap();
+
+        for &x in &[0.0, 10.0, 20.0] {
+            let pixel = target.get_image_data(x, 0.0, 1.0, 1.0).unwrap().to_vec();
+            assert_eq!(pixel, [0, 255, 0, 255]);
+        }
+    }
+
+    #[test]
+    fn test_image_data_default_color_space() {
+        let canvas = new_canvas();
+        let image_data = canvas.create_image_data(2 as f64, 2 as f64).unwrap();
+        assert_eq!(image_data.color_space(), PredefinedColorSpace::Srgb);
+
+        let image_data = canvas.create_image_data_with_color_space(2 as f64, 2 as f64, PredefinedColorSpace::Srgb).unwrap();
+        assert_eq!(image_data.color_space(), PredefinedColorSpace::Srgb);
+    }
+
+    #[test]
+    fn test_image_data_display_p3_color_space() {
+        let canvas = new_canvas();
+        let supported: bool = js! (
+            return "colorSpace" in ImageData.prototype;
+        ).try_into().unwrap();
+
+        let image_data = canvas.create_image_data_with_color_space(2 as f64, 2 as f64, PredefinedColorSpace::DisplayP3).unwrap();
+        if supported {
+            assert_eq!(image_data.color_space(), PredefinedColorSpace::DisplayP3);
+        } else {
+            assert_eq!(image_data.color_space(), PredefinedColorSpace::Srgb);
+        }
+    }
 }
@@ -0,0 +1,308 @@
+use std;
+use webcore::value::{Reference, Value};
+use webcore::mutfn::Mut;
+use webcore::try_from::TryInto;
+use webapi::element::{IElement, Element};
+use webapi::error::TypeError;
+
+/// Provides a way to receive notifications about changes to the size of elements.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver)
+// https://drafts.csswg.org/resize-observer/#resize-observer-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ResizeObserver")]
+pub struct ResizeObserver( Reference );
+
+/// Specifies which box model a [`ResizeObserver`](struct.ResizeObserver.html) observes.
+///
+/// This is only used with the [`ResizeObserver::observe_with_options`](struct.ResizeObserver.html#method.observe_with_options) method.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe#Parameters)
+// https://drafts.csswg.org/resize-observer/#enumdef-resizeobserverboxoptions
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ResizeBox {
+    /// The size of the content area as defined in CSS. (default)
+    ContentBox,
+    /// The size of the box border area as defined in CSS.
+    BorderBox,
+    /// The size of the content area in device pixels, before any CSS transforms are applied.
+    DevicePixelContentBox
+}
+
+impl ResizeObserver {
+    /// Returns a new [`ResizeObserverHandle`](struct.ResizeObserverHandle.html) with the given callback.
+    ///
+    /// The callback will be called with the following arguments when the observed elements are resized:
+    ///
+    /// 1. A vector of entries describing the new sizes of the resized elements.
+    ///
+    /// 2. The `ResizeObserver`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/ResizeObserver)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-resizeobserver
+    pub fn new< F >( callback: F ) -> ResizeObserverHandle
+        where F: FnMut( Vec< ResizeObserverEntry >, Self ) + 'static {
+        let callback_reference: Reference = js! ( return @{Mut(callback)}; ).try_into().unwrap();
+
+        ResizeObserverHandle {
+            callback_reference: callback_reference.clone(),
+
+            resize_observer: js! (
+                return new ResizeObserver( @{callback_reference} );
+            ).try_into().unwrap(),
+        }
+    }
+
+    /// Starts observing changes to the content box size of the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-observe
+    pub fn observe< T: IElement >( &self, target: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.observe( @{target.as_ref()} );
+        }
+    }
+
+    /// Starts observing changes to the size of the given box of the `target`.
+    ///
+    /// If you call this on the same `target` multiple times, the new box model
+    /// replaces the old one.
+    ///
+    /// Returns a `TypeError` if the browser doesn't support observing the given box,
+    /// e.g. [DevicePixelContentBox](enum.ResizeBox.html#variant.DevicePixelContentBox).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/observe)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-observe
+    pub fn observe_with_options< T: IElement >( &self, target: &T, box_model: ResizeBox ) -> Result< (), TypeError > {
+        let box_model = match box_model {
+            ResizeBox::ContentBox => "content-box",
+            ResizeBox::BorderBox => "border-box",
+            ResizeBox::DevicePixelContentBox => "device-pixel-content-box",
+        };
+
+        js_try!( @(no_return)
+            @{self.as_ref()}.observe( @{target.as_ref()}, { box: @{box_model} } );
+        ).unwrap()
+    }
+
+    /// Stops observing the `target`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/unobserve)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-unobserve
+    pub fn unobserve< T: IElement >( &self, target: &T ) {
+        js! { @(no_return)
+            @{self.as_ref()}.unobserve( @{target.as_ref()} );
+        }
+    }
+
+    /// Stops observing all targets.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserver/disconnect)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserver-disconnect
+    pub fn disconnect( &self ) {
+        js! { @(no_return)
+            @{self.as_ref()}.disconnect();
+        }
+    }
+}
+
+/// A wrapper which ensures that memory is properly cleaned up when it's no longer needed.
+///
+/// This is created by the [`ResizeObserver::new`](struct.ResizeObserver.html#method.new) method, and
+/// it can use the same methods as [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// When the `ResizeObserverHandle` is dropped, the [`disconnect`](#method.disconnect)
+/// method will automatically be called.
+#[ derive( Debug ) ]
+pub struct ResizeObserverHandle {
+    resize_observer: ResizeObserver,
+    callback_reference: Reference,
+}
+
+impl std::ops::Deref for ResizeObserverHandle {
+    type Target = ResizeObserver;
+
+    #[inline]
+    fn deref( &self ) -> &Self::Target {
+        &self.resize_observer
+    }
+}
+
+impl Drop for ResizeObserverHandle {
+    #[inline]
+    fn drop( &mut self ) {
+        self.disconnect();
+
+        js! { @(no_return)
+            @{&self.callback_reference}.drop();
+        }
+    }
+}
+
+/// The size of a box observed by a [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverSize)
+// https://drafts.csswg.org/resize-observer/#resizeobserversize
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ResizeObserverSize {
+    /// The length of the box in the inline direction (the width, for horizontal writing modes).
+    pub inline_size: f64,
+    /// The length of the box in the block direction (the height, for horizontal writing modes).
+    pub block_size: f64
+}
+
+/// Describes the new size of an element observed by a [`ResizeObserver`](struct.ResizeObserver.html).
+///
+/// It is passed to the [`ResizeObserver`](struct.ResizeObserver.html)'s callback.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry)
+// https://drafts.csswg.org/resize-observer/#resize-observer-entry-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ResizeObserverEntry")]
+pub struct ResizeObserverEntry( Reference );
+
+impl ResizeObserverEntry {
+    /// The element which was resized.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/target)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-target
+    pub fn target( &self ) -> Element {
+        js!(
+            return @{self}.target;
+        ).try_into().unwrap()
+    }
+
+    /// The new border box size of the element, with one entry per fragment.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/borderBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-borderboxsize
+    pub fn border_box_size( &self ) -> Vec< ResizeObserverSize > {
+        box_sizes( js!( return @{self}.borderBoxSize; ) )
+    }
+
+    /// The new content box size of the element, with one entry per fragment.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/contentBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-contentboxsize
+    pub fn content_box_size( &self ) -> Vec< ResizeObserverSize > {
+        box_sizes( js!( return @{self}.contentBoxSize; ) )
+    }
+
+    /// The new content box size of the element in device pixels, with one entry per fragment.
+    ///
+    /// This is empty in browsers which don't support observing device pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ResizeObserverEntry/devicePixelContentBoxSize)
+    // https://drafts.csswg.org/resize-observer/#dom-resizeobserverentry-devicepixelcontentboxsize
+    pub fn device_pixel_content_box_size( &self ) -> Vec< ResizeObserverSize > {
+        box_sizes( js!( return @{self}.devicePixelContentBoxSize; ) )
+    }
+}
+
+// Older browsers return a single `ResizeObserverSize` instead of an array of them.
+fn box_sizes( sizes: Value ) -> Vec< ResizeObserverSize > {
+    let sizes: Vec< f64 > = js!(
+        var sizes = @{sizes};
+        if( sizes === undefined || sizes === null ) {
+            return [];
+        }
+        if( sizes.length === undefined ) {
+            sizes = [ sizes ];
+        }
+
+        var output = [];
+        for( var i = 0; i < sizes.length; ++i ) {
+            output.push( sizes[ i ].inlineSize, sizes[ i ].blockSize );
+        }
+        return output;
+    ).try_into().unwrap();
+
+    sizes.chunks( 2 ).map( |size| ResizeObserverSize {
+        inline_size: size[ 0 ],
+        block_size: size[ 1 ]
+    }).collect()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::node::INode;
+    #[cfg(rust_nightly)]
+    use async_test;
+
+    #[test]
+    fn test_observe_with_options() {
+        let canvas = document().create_element( "canvas" ).unwrap();
+        document().body().unwrap().append_child( &canvas );
+
+        let observer = ResizeObserver::new( |_, _| {} );
+        observer.observe_with_options( &canvas, ResizeBox::BorderBox ).unwrap();
+        observer.unobserve( &canvas );
+
+        document().body().unwrap().remove_child( &canvas ).unwrap();
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_observe_device_pixel_content_box< F: FnOnce() >( done: F ) {
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use webapi::global::set_timeout;
+
+        let canvas = document().create_element( "canvas" ).unwrap();
+        document().body().unwrap().append_child( &canvas );
+
+        let supported = ResizeObserver::new( |_, _| {} )
+            .observe_with_options( &canvas, ResizeBox::DevicePixelContentBox )
+            .is_ok();
+
+        if !supported {
+            // The browser can't observe device pixels.
+            document().body().unwrap().remove_child( &canvas ).unwrap();
+            done();
+            return;
+        }
+
+        let handle: Rc< RefCell< Option< ResizeObserverHandle > > > = Rc::new( RefCell::new( None ) );
+        let mut done = Some( done );
+        let observer = ResizeObserver::new( {
+            let handle = handle.clone();
+            let canvas = canvas.clone();
+            move |entries, _| {
+                if let Some( done ) = done.take() {
+                    assert_eq!( entries.len(), 1 );
+                    assert_eq!( entries[ 0 ].target(), canvas );
+
+                    let sizes = entries[ 0 ].device_pixel_content_box_size();
+                    assert_eq!( sizes.len(), 1 );
+                    assert!( sizes[ 0 ].inline_size > 0.0 );
+                    assert!( sizes[ 0 ].block_size > 0.0 );
+
+                    // The observer can't be dropped from within its own callback.
+                    let handle = handle.clone();
+                    let canvas = canvas.clone();
+                    set_timeout( move || {
+                        handle.borrow_mut().take().unwrap();
+                        document().body().unwrap().remove_child( &canvas ).unwrap();
+                        done();
+                    }, 0 );
+                }
+            }
+        });
+
+        observer.observe_with_options( &canvas, ResizeBox::DevicePixelContentBox ).unwrap();
+        *handle.borrow_mut() = Some( observer );
+    }
+
+    #[test]
+    fn test_box_sizes() {
+        let sizes = js!( return [ { inlineSize: 300, blockSize: 150 } ]; );
+        assert_eq!( box_sizes( sizes ), vec![ ResizeObserverSize { inline_size: 300.0, block_size: 150.0 } ] );
+
+        let size = js!( return { inlineSize: 600, blockSize: 300 }; );
+        assert_eq!( box_sizes( size ), vec![ ResizeObserverSize { inline_size: 600.0, block_size: 300.0 } ] );
+
+        assert_eq!( box_sizes( Value::Undefined ), vec![] );
+    }
+}
@@ -0,0 +1,108 @@
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use webcore::try_from::TryInto;
+use webapi::window::window;
+use webapi::event_target::{IEventTarget, EventListenerHandle};
+use webapi::events::history::{HashChangeEvent, PopStateEvent};
+
+/// A helper for single page applications which calls a callback with the
+/// current path every time the user navigates within the page.
+///
+/// The path is the URL fragment without its leading `#` (so `#/active` becomes `/active`),
+/// or the URL's pathname if the fragment is empty. It's recomputed on every
+/// `hashchange` and `popstate` event, and the callback is only called when it
+/// actually changed, since browsers usually fire both events for a single navigation.
+///
+/// The event listeners are removed when the `Router` is dropped.
+#[must_use = "the router stops listening for navigation when dropped"]
+#[derive(Debug)]
+pub struct Router {
+    hash_change: Option< EventListenerHandle >,
+    pop_state: Option< EventListenerHandle >
+}
+
+impl Router {
+    /// Starts listening for navigation.
+    ///
+    /// The callback isn't called for the initial path; use
+    /// [current_path](#method.current_path) to route the initial page load.
+    pub fn new< F >( callback: F ) -> Self where F: FnMut( String ) + 'static {
+        let state = Rc::new( RefCell::new( (Self::current_path(), callback) ) );
+
+        let on_navigate = move || {
+            let path = Self::current_path();
+            let &mut (ref mut last_path, ref mut callback) = &mut *state.borrow_mut();
+            if *last_path != path {
+                *last_path = path.clone();
+                callback( path );
+            }
+        };
+
+        let on_hash_change = on_navigate.clone();
+        let hash_change = window().add_event_listener( move |_: HashChangeEvent| on_hash_change() );
+        let pop_state = window().add_event_listener( move |_: PopStateEvent| on_navigate() );
+
+        Router {
+            hash_change: Some( hash_change ),
+            pop_state: Some( pop_state )
+        }
+    }
+
+    /// Returns the path the router would currently pass to its callback.
+    pub fn current_path() -> String {
+        js!(
+            var location = window.location;
+            if( location.hash.length > 1 ) {
+                return location.hash.substring( 1 );
+            }
+            return location.pathname;
+        ).try_into().unwrap()
+    }
+}
+
+impl Drop for Router {
+    fn drop( &mut self ) {
+        if let Some( handle ) = self.hash_change.take() {
+            handle.remove();
+        }
+
+        if let Some( handle ) = self.pop_state.take() {
+            handle.remove();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_router() {
+        let paths = Rc::new( RefCell::new( Vec::new() ) );
+        let router = {
+            let paths = paths.clone();
+            Router::new( move |path| paths.borrow_mut().push( path ) )
+        };
+
+        let navigate = |hash: &str| {
+            js!( @(no_return)
+                window.location.hash = @{hash};
+                window.dispatchEvent( new HashChangeEvent( "hashchange" ) );
+            );
+        };
+
+        navigate( "#/active" );
+        assert_eq!( Router::current_path(), "/active" );
+        assert_eq!( *paths.borrow(), vec![ "/active".to_owned() ] );
+
+        js!( @(no_return) window.dispatchEvent( new PopStateEvent( "popstate" ) ); );
+        assert_eq!( paths.borrow().len(), 1 );
+
+        drop( router );
+        navigate( "#/completed" );
+        assert_eq!( paths.borrow().len(), 1 );
+
+        navigate( "" );
+    }
+}
@@ -0,0 +1,200 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::event_target::{IEventTarget, EventTarget};
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+
+/// The `Screen` interface represents the screen on which the current window is being rendered.
+///
+/// It can be obtained with [Window::screen](struct.Window.html#method.screen).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen)
+// https://drafts.csswg.org/cssom-view/#the-screen-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "Screen")]
+pub struct Screen( Reference );
+
+impl Screen {
+    /// Returns the width of the screen in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/width)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-width
+    pub fn width( &self ) -> i32 {
+        js!(
+            return @{self}.width;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the height of the screen in CSS pixels.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/height)
+    // https://drafts.csswg.org/cssom-view/#dom-screen-height
+    pub fn height( &self ) -> i32 {
+        js!(
+            return @{self}.height;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the current orientation of the screen, or `None` if the browser
+    /// doesn't support the Screen Orientation API.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Screen/orientation)
+    // https://w3c.github.io/screen-orientation/#dom-screen-orientation
+    pub fn orientation( &self ) -> Option< ScreenOrientation > {
+        js!(
+            return @{self}.orientation;
+        ).try_into().ok()
+    }
+}
+
+/// The orientation of the screen, as returned by [ScreenOrientation::type_](struct.ScreenOrientation.html#method.type_).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/type)
+// https://w3c.github.io/screen-orientation/#dom-orientationtype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OrientationType {
+    /// The screen is in its primary portrait mode.
+    PortraitPrimary,
+    /// The screen is in its secondary portrait mode, i.e. upside down.
+    PortraitSecondary,
+    /// The screen is in its primary landscape mode.
+    LandscapePrimary,
+    /// The screen is in its secondary landscape mode, i.e. rotated by 180 degrees from the primary one.
+    LandscapeSecondary
+}
+
+/// An orientation to which the screen can be locked with [ScreenOrientation::lock](struct.ScreenOrientation.html#method.lock).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock)
+// https://w3c.github.io/screen-orientation/#dom-orientationlocktype
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OrientationLockType {
+    /// Any orientation.
+    Any,
+    /// The natural orientation of the device.
+    Natural,
+    /// Either of the landscape orientations.
+    Landscape,
+    /// Either of the portrait orientations.
+    Portrait,
+    /// The primary portrait orientation.
+    PortraitPrimary,
+    /// The secondary portrait orientation.
+    PortraitSecondary,
+    /// The primary landscape orientation.
+    LandscapePrimary,
+    /// The secondary landscape orientation.
+    LandscapeSecondary
+}
+
+impl OrientationLockType {
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    fn as_str( &self ) -> &str {
+        match *self {
+            OrientationLockType::Any => "any",
+            OrientationLockType::Natural => "natural",
+            OrientationLockType::Landscape => "landscape",
+            OrientationLockType::Portrait => "portrait",
+            OrientationLockType::PortraitPrimary => "portrait-primary",
+            OrientationLockType::PortraitSecondary => "portrait-secondary",
+            OrientationLockType::LandscapePrimary => "landscape-primary",
+            OrientationLockType::LandscapeSecondary => "landscape-secondary"
+        }
+    }
+}
+
+/// The `ScreenOrientation` interface provides information about the current orientation
+/// of the screen.
+///
+/// A [ChangeEvent](event/struct.ChangeEvent.html) is fired on it when the orientation changes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation)
+// https://w3c.github.io/screen-orientation/#screenorientation-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ScreenOrientation")]
+#[reference(subclass_of(EventTarget))]
+pub struct ScreenOrientation( Reference );
+
+impl IEventTarget for ScreenOrientation {}
+
+impl ScreenOrientation {
+    /// Returns the current orientation angle of the screen in degrees.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/angle)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-angle
+    pub fn angle( &self ) -> u16 {
+        js!(
+            return @{self}.angle;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the current orientation type of the screen.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/type)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-type
+    pub fn type_( &self ) -> OrientationType {
+        let kind: String = js!(
+            return @{self}.type;
+        ).try_into().unwrap();
+
+        match kind.as_str() {
+            "portrait-primary" => OrientationType::PortraitPrimary,
+            "portrait-secondary" => OrientationType::PortraitSecondary,
+            "landscape-primary" => OrientationType::LandscapePrimary,
+            "landscape-secondary" => OrientationType::LandscapeSecondary,
+            _ => unreachable!( "Unexpected value of ScreenOrientation::type: {}", kind )
+        }
+    }
+
+    /// Locks the orientation of the screen to the given orientation.
+    ///
+    /// This usually only works for fullscreen documents on mobile devices; elsewhere
+    /// the returned future resolves to an `Err`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-lock
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn lock( &self, orientation: OrientationLockType ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.lock( @{orientation.as_str()} );
+        ).try_into().unwrap()
+    }
+
+    /// Unlocks the orientation of the screen, undoing a previous [lock](#method.lock).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/unlock)
+    // https://w3c.github.io/screen-orientation/#dom-screenorientation-unlock
+    pub fn unlock( &self ) {
+        js! { @(no_return)
+            @{self}.unlock();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+
+    #[test]
+    fn test_screen() {
+        let screen = window().screen();
+        assert!( screen.width() >= 0 );
+        assert!( screen.height() >= 0 );
+
+        if let Some( orientation ) = screen.orientation() {
+            // This panics if the browser returns an unknown orientation type.
+            orientation.type_();
+            assert!( orientation.angle() < 360 );
+        }
+    }
+
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[test]
+    fn test_orientation_lock_type() {
+        use super::OrientationLockType;
+
+        assert_eq!( OrientationLockType::Any.as_str(), "any" );
+        assert_eq!( OrientationLockType::LandscapeSecondary.as_str(), "landscape-secondary" );
+    }
+}
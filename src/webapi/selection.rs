@@ -291,6 +291,17 @@ impl Selection {
             return @{self}.containsNode(@{node.as_ref()}, true);
         ).try_into().unwrap()
     }
+
+    /// Returns the text which is currently selected.
+    ///
+    /// [(Javascript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Selection/toString)
+    // https://w3c.github.io/selection-api/#dom-selection-stringifier
+    #[inline]
+    pub fn to_string(&self) -> String {
+        js! (
+            return @{self}.toString();
+        ).try_into().unwrap()
+    }
 }
 
 /// The Range interface represents a fragment of a document that can contain nodes and parts of
@@ -439,4 +450,24 @@ mod tests {
         selection().select_all_children(&parent);
         assert!(selection().contains_whole(&text_node));
     }
+
+    #[test]
+    fn test_to_string() {
+        let parent = div();
+        parent.append_child(&text("selected text"));
+
+        let range: Range = js!(
+            var range = document.createRange();
+            range.selectNodeContents(@{&parent});
+            return range;
+        ).try_into().unwrap();
+
+        selection().remove_all_ranges();
+        selection().add_range(&range);
+        assert_eq!(selection().to_string(), "selected text");
+        assert_eq!(document().get_selection().unwrap(), selection());
+
+        selection().remove_all_ranges();
+        assert_eq!(selection().to_string(), "");
+    }
 }
@@ -2,6 +2,54 @@ use webcore::value::Reference;
 use webcore::try_from::TryInto;
 use private::TODO;
 
+#[cfg(feature = "serde")]
+use serde_crate::Serialize;
+#[cfg(feature = "serde")]
+use serde_crate::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use ecosystem::serde::{ConversionError, to_value, from_value};
+#[cfg(feature = "serde")]
+use webapi::json::json_parse;
+#[cfg(feature = "serde")]
+use webapi::dom_exception::QuotaExceededError;
+#[cfg(feature = "serde")]
+use std::{fmt, error};
+
+/// An error returned from [Storage::insert_serde](struct.Storage.html#method.insert_serde).
+// This can't use `error_enum_boilerplate!` since `ConversionError` isn't a JavaScript exception.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone)]
+pub enum InsertSerdeError {
+    /// The value couldn't be serialized.
+    ConversionError( ConversionError ),
+    /// The storage is full.
+    QuotaExceededError( QuotaExceededError )
+}
+
+#[cfg(feature = "serde")]
+impl From< ConversionError > for InsertSerdeError {
+    fn from( error: ConversionError ) -> Self {
+        InsertSerdeError::ConversionError( error )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for InsertSerdeError {
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        match *self {
+            InsertSerdeError::ConversionError( ref error ) => error.fmt( formatter ),
+            InsertSerdeError::QuotaExceededError( ref error ) => error.fmt( formatter )
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl error::Error for InsertSerdeError {
+    fn description( &self ) -> &str {
+        "InsertSerdeError"
+    }
+}
+
 /// The `Storage` interface of the Web Storage API provides access to
 /// the session storage or local storage for a particular domain.
 ///
@@ -40,6 +88,32 @@ impl Storage {
         Ok(())
     }
 
+    /// Serializes `value` into JSON and inserts it into the storage under the given key.
+    ///
+    /// The value can be read back with [get_serde](#method.get_serde).
+    ///
+    /// Fails with a `ConversionError` if the value can't be serialized,
+    /// or with a `QuotaExceededError` if the storage is full.
+    #[cfg(feature = "serde")]
+    pub fn insert_serde< T: Serialize >( &self, key: &str, value: &T ) -> Result< (), InsertSerdeError > {
+        let value = to_value( value )?;
+        let result: Result< (), QuotaExceededError > = js_try!( @(no_return)
+            @{self}.setItem( @{key}, JSON.stringify( @{value} ) );
+        ).unwrap();
+
+        result.map_err( InsertSerdeError::QuotaExceededError )
+    }
+
+    /// Returns the value corresponding to the key deserialized from JSON.
+    ///
+    /// Returns `None` if the key isn't present or its value can't be deserialized into `T`.
+    #[cfg(feature = "serde")]
+    pub fn get_serde< T: DeserializeOwned >( &self, key: &str ) -> Option< T > {
+        let text = self.get( key )?;
+        let value = json_parse( &text ).ok()?;
+        from_value( value ).ok()
+    }
+
     /// Removes a key from the storage.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Storage/removeItem)
@@ -72,4 +146,129 @@ impl Storage {
     pub fn contains_key( &self, key: &str ) -> bool {
         js!( return !!@{self}.getItem( @{key} ); ).try_into().unwrap()
     }
+
+    /// Returns an iterator over the key-value pairs in the storage.
+    ///
+    /// The order of the keys is user-agent defined. Modifying the storage
+    /// while iterating over it may skip or repeat entries.
+    pub fn iter( &self ) -> StorageIter {
+        StorageIter {
+            storage: self.clone(),
+            index: 0
+        }
+    }
+}
+
+impl< 'a > IntoIterator for &'a Storage {
+    type Item = (String, String);
+    type IntoIter = StorageIter;
+
+    #[inline]
+    fn into_iter( self ) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key-value pairs of a [Storage](struct.Storage.html).
+///
+/// This is created by the [Storage::iter](struct.Storage.html#method.iter) method.
+#[derive(Debug)]
+pub struct StorageIter {
+    storage: Storage,
+    index: u32
+}
+
+impl Iterator for StorageIter {
+    type Item = (String, String);
+    fn next( &mut self ) -> Option< Self::Item > {
+        while self.index < self.storage.len() {
+            let index = self.index;
+            self.index += 1;
+
+            if let Some( key ) = self.storage.key( index ) {
+                if let Some( value ) = self.storage.get( &key ) {
+                    return Some( (key, value) );
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use webapi::window::window;
+
+    #[test]
+    fn test_iter() {
+        let storage = window().session_storage();
+        storage.clear();
+        storage.insert( "a", "1" ).unwrap();
+        storage.insert( "b", "2" ).unwrap();
+
+        assert_eq!( storage.len(), 2 );
+        assert!( storage.key( 2 ).is_none() );
+
+        let mut entries: Vec< _ > = storage.iter().collect();
+        entries.sort();
+        assert_eq!( entries, vec![
+            ("a".to_owned(), "1".to_owned()),
+            ("b".to_owned(), "2".to_owned())
+        ]);
+
+        storage.clear();
+        assert_eq!( storage.iter().count(), 0 );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+        struct Entry {
+            title: String,
+            completed: bool,
+            tags: Vec< String >
+        }
+
+        let storage = window().session_storage();
+        let entry = Entry {
+            title: "Hello".to_owned(),
+            completed: true,
+            tags: vec![ "a".to_owned(), "b".to_owned() ]
+        };
+
+        storage.insert_serde( "entry", &entry ).unwrap();
+        assert_eq!( storage.get_serde::< Entry >( "entry" ), Some( entry ) );
+
+        storage.insert( "entry", "{" ).unwrap();
+        assert_eq!( storage.get_serde::< Entry >( "entry" ), None );
+
+        storage.remove( "entry" );
+        assert_eq!( storage.get_serde::< Entry >( "entry" ), None );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_insert_serde_quota_exceeded() {
+        use super::InsertSerdeError;
+
+        let storage = window().session_storage();
+        storage.clear();
+
+        let chunk: String = ::std::iter::repeat( 'x' ).take( 1024 * 1024 ).collect();
+        let mut result = Ok(());
+        for index in 0..64 {
+            result = storage.insert_serde( &index.to_string(), &chunk );
+            if result.is_err() {
+                break;
+            }
+        }
+
+        storage.clear();
+        match result {
+            Err( InsertSerdeError::QuotaExceededError( _ ) ) => {},
+            result => panic!( "unexpected result: {:?}", result )
+        }
+    }
 }
@@ -0,0 +1,376 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webapi::error::TypeError;
+
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::promise_future::PromiseFuture;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webapi::typed_array::TypedArray;
+#[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+use webcore::serialization::JsSerialize;
+
+/// A readable stream of data, e.g. the contents of a [Blob](struct.Blob.html)
+/// returned by [IBlob::stream](trait.IBlob.html#method.stream).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream)
+// https://streams.spec.whatwg.org/#rs-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ReadableStream")]
+pub struct ReadableStream( Reference );
+
+impl ReadableStream {
+    /// Returns whether the stream is locked to a reader.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/locked)
+    // https://streams.spec.whatwg.org/#rs-locked
+    pub fn locked( &self ) -> bool {
+        js!(
+            return @{self}.locked;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a reader and locks the stream to it. The stream stays locked
+    /// until [release_lock](struct.ReadableStreamDefaultReader.html#method.release_lock)
+    /// is called on the reader.
+    ///
+    /// Returns a `TypeError` if the stream is already locked.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/getReader)
+    // https://streams.spec.whatwg.org/#rs-get-reader
+    pub fn get_reader( &self ) -> Result< ReadableStreamDefaultReader, TypeError > {
+        js_try!(
+            return @{self}.getReader();
+        ).unwrap()
+    }
+
+    /// Pipes this stream through the given transform, and returns the
+    /// transformed stream.
+    ///
+    /// This locks both this stream and the writable side of the transform.
+    ///
+    /// Returns a `TypeError` if either of them is already locked.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/pipeThrough)
+    // https://streams.spec.whatwg.org/#rs-pipe-through
+    pub fn pipe_through( &self, transform: &TransformStream ) -> Result< ReadableStream, TypeError > {
+        js_try!(
+            return @{self}.pipeThrough( @{transform} );
+        ).unwrap()
+    }
+
+    /// Pipes all of the chunks of this stream into the given writable stream.
+    ///
+    /// The future resolves once all of the chunks were written and the destination was closed,
+    /// and fails if either of the streams errors or is already locked.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStream/pipeTo)
+    // https://streams.spec.whatwg.org/#rs-pipe-to
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn pipe_to( &self, destination: &WritableStream ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.pipeTo( @{destination} ).then( function() {} );
+        ).try_into().unwrap()
+    }
+}
+
+/// A reader which reads chunks of data from a [ReadableStream](struct.ReadableStream.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader)
+// https://streams.spec.whatwg.org/#default-reader-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "ReadableStreamDefaultReader")]
+pub struct ReadableStreamDefaultReader( Reference );
+
+impl ReadableStreamDefaultReader {
+    /// Reads the next chunk of bytes from the stream.
+    ///
+    /// The future resolves to `None` once the stream is exhausted.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/read)
+    // https://streams.spec.whatwg.org/#default-reader-read
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn read( &self ) -> PromiseFuture< Option< TypedArray< u8 > > > {
+        js!(
+            return @{self}.read().then( function( result ) {
+                return result.done ? null : result.value;
+            });
+        ).try_into().unwrap()
+    }
+
+    /// Releases the reader's lock on the stream, so that another reader can be created.
+    ///
+    /// Returns a `TypeError` if there are still pending reads.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/releaseLock)
+    // https://streams.spec.whatwg.org/#default-reader-release-lock
+    pub fn release_lock( &self ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.releaseLock();
+        ).unwrap()
+    }
+}
+
+/// A destination for streaming data, e.g. the sink of a streamed upload.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStream)
+// https://streams.spec.whatwg.org/#ws-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "WritableStream")]
+pub struct WritableStream( Reference );
+
+impl WritableStream {
+    /// Returns whether the stream is locked to a writer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStream/locked)
+    // https://streams.spec.whatwg.org/#ws-locked
+    pub fn locked( &self ) -> bool {
+        js!(
+            return @{self}.locked;
+        ).try_into().unwrap()
+    }
+
+    /// Creates a writer and locks the stream to it. The stream stays locked
+    /// until [release_lock](struct.WritableStreamDefaultWriter.html#method.release_lock)
+    /// is called on the writer.
+    ///
+    /// Returns a `TypeError` if the stream is already locked.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStream/getWriter)
+    // https://streams.spec.whatwg.org/#ws-get-writer
+    pub fn get_writer( &self ) -> Result< WritableStreamDefaultWriter, TypeError > {
+        js_try!(
+            return @{self}.getWriter();
+        ).unwrap()
+    }
+}
+
+/// A writer which writes chunks of data into a [WritableStream](struct.WritableStream.html).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStreamDefaultWriter)
+// https://streams.spec.whatwg.org/#default-writer-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "WritableStreamDefaultWriter")]
+pub struct WritableStreamDefaultWriter( Reference );
+
+impl WritableStreamDefaultWriter {
+    /// Writes a chunk of data into the stream.
+    ///
+    /// The future resolves once the chunk was accepted by the underlying sink.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStreamDefaultWriter/write)
+    // https://streams.spec.whatwg.org/#default-writer-write
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn write< T: JsSerialize >( &self, chunk: T ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.write( @{chunk} ).then( function() {} );
+        ).try_into().unwrap()
+    }
+
+    /// Closes the stream once all of the previously written chunks were processed.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStreamDefaultWriter/close)
+    // https://streams.spec.whatwg.org/#default-writer-close
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn close( &self ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.close().then( function() {} );
+        ).try_into().unwrap()
+    }
+
+    /// Aborts the stream, discarding any chunks which weren't processed yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStreamDefaultWriter/abort)
+    // https://streams.spec.whatwg.org/#default-writer-abort
+    #[cfg(all(feature = "futures-support", feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    pub fn abort( &self ) -> PromiseFuture< () > {
+        js!(
+            return @{self}.abort().then( function() {} );
+        ).try_into().unwrap()
+    }
+
+    /// Releases the writer's lock on the stream, so that another writer can be created.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/WritableStreamDefaultWriter/releaseLock)
+    // https://streams.spec.whatwg.org/#default-writer-release-lock
+    pub fn release_lock( &self ) {
+        js! { @(no_return)
+            @{self}.releaseLock();
+        }
+    }
+}
+
+/// A pair of a writable and a readable stream, where the chunks written into the
+/// [writable](#method.writable) side can be read, transformed, from the [readable](#method.readable) side.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransformStream)
+// https://streams.spec.whatwg.org/#ts-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TransformStream")]
+pub struct TransformStream( Reference );
+
+impl TransformStream {
+    /// Creates an identity transform, which passes all of the chunks through unchanged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransformStream/TransformStream)
+    // https://streams.spec.whatwg.org/#ts-constructor
+    pub fn new() -> Self {
+        js!(
+            return new TransformStream();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the readable side of the transform.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransformStream/readable)
+    // https://streams.spec.whatwg.org/#ts-readable
+    pub fn readable( &self ) -> ReadableStream {
+        js!(
+            return @{self}.readable;
+        ).try_into().unwrap()
+    }
+
+    /// Returns the writable side of the transform.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TransformStream/writable)
+    // https://streams.spec.whatwg.org/#ts-writable
+    pub fn writable( &self ) -> WritableStream {
+        js!(
+            return @{self}.writable;
+        ).try_into().unwrap()
+    }
+}
+
+impl Default for TransformStream {
+    #[inline]
+    fn default() -> Self {
+        TransformStream::new()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::blob::{IBlob, Blob};
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use async_test;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use webcore::promise_future::spawn_local;
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    use futures_util::FutureExt;
+
+    #[test]
+    fn test_blob_stream_reader_lock() {
+        let blob: Blob = js!( return new Blob( [ "hello" ] ); ).try_into().unwrap();
+        let stream = blob.stream();
+        assert!( !stream.locked() );
+
+        let reader = stream.get_reader().unwrap();
+        assert!( stream.locked() );
+        assert!( stream.get_reader().is_err() );
+
+        reader.release_lock().unwrap();
+        assert!( !stream.locked() );
+        stream.get_reader().unwrap();
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_blob_stream_read< F: FnOnce() >( done: F ) {
+        let blob: Blob = js!( return new Blob( [ "hello" ] ); ).try_into().unwrap();
+        let reader = blob.stream().get_reader().unwrap();
+
+        spawn_local( reader.read().then( move |chunk| {
+            assert_eq!( chunk.unwrap().unwrap().to_vec(), b"hello".to_vec() );
+            reader.read()
+        }).map( move |chunk| {
+            assert!( chunk.unwrap().is_none() );
+            done();
+        }));
+    }
+
+    #[test]
+    fn test_writable_stream_writer_lock() {
+        let stream: WritableStream = js!( return new WritableStream(); ).try_into().unwrap();
+        assert!( !stream.locked() );
+
+        let writer = stream.get_writer().unwrap();
+        assert!( stream.locked() );
+        assert!( stream.get_writer().is_err() );
+
+        writer.release_lock();
+        assert!( !stream.locked() );
+        stream.get_writer().unwrap();
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_writable_stream_write_and_close< F: FnOnce() >( done: F ) {
+        use futures_util::future::join3;
+
+        let chunks = js!( return []; );
+        let stream: WritableStream = js!(
+            var chunks = @{&chunks};
+            return new WritableStream( {
+                write: function( chunk ) {
+                    chunks.push( chunk );
+                }
+            });
+        ).try_into().unwrap();
+
+        let writer = stream.get_writer().unwrap();
+        let first = writer.write( "first" );
+        let second = writer.write( "second" );
+        let close = writer.close();
+
+        spawn_local( join3( first, second, close ).map( move |(first, second, close)| {
+            first.unwrap();
+            second.unwrap();
+            close.unwrap();
+
+            let chunks: Vec< String > = chunks.try_into().unwrap();
+            assert_eq!( chunks, vec![ "first".to_owned(), "second".to_owned() ] );
+            done();
+        }));
+    }
+
+    #[test]
+    fn test_pipe_through() {
+        let blob: Blob = js!( return new Blob( [ "hello" ] ); ).try_into().unwrap();
+        let source = blob.stream();
+        let transform = TransformStream::new();
+
+        let output = source.pipe_through( &transform ).unwrap();
+        assert_eq!( output, transform.readable() );
+        assert!( source.locked() );
+        assert!( transform.writable().locked() );
+        assert!( !output.locked() );
+
+        assert!( source.pipe_through( &TransformStream::new() ).is_err() );
+    }
+
+    #[cfg(all(rust_nightly, feature = "experimental_features_which_may_break_on_minor_version_bumps"))]
+    #[async_test]
+    fn test_pipe_through_into_writable_stream< F: FnOnce() >( done: F ) {
+        let blob: Blob = js!( return new Blob( [ "hello" ] ); ).try_into().unwrap();
+        let bytes = js!( return []; );
+        let destination: WritableStream = js!(
+            var bytes = @{&bytes};
+            return new WritableStream( {
+                write: function( chunk ) {
+                    for( var i = 0; i < chunk.length; ++i ) {
+                        bytes.push( chunk[ i ] );
+                    }
+                }
+            });
+        ).try_into().unwrap();
+
+        let output = blob.stream().pipe_through( &TransformStream::default() ).unwrap();
+        spawn_local( output.pipe_to( &destination ).map( move |result| {
+            result.unwrap();
+
+            let bytes: Vec< u8 > = bytes.try_into().unwrap();
+            assert_eq!( bytes, b"hello".to_vec() );
+            done();
+        }));
+    }
+}
@@ -0,0 +1,116 @@
+use webcore::value::Reference;
+use webcore::try_from::TryInto;
+use webcore::unsafe_typed_array::UnsafeTypedArray;
+use webapi::typed_array::TypedArray;
+use webapi::error::RangeError;
+
+/// A decoder which converts bytes in a specific text encoding, like `"utf-8"`,
+/// `"windows-1252"` or `"shift-jis"`, into a string.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder)
+// https://encoding.spec.whatwg.org/#interface-textdecoder
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TextDecoder")]
+pub struct TextDecoder( Reference );
+
+impl TextDecoder {
+    /// Creates a new `TextDecoder` for the encoding with the given label.
+    ///
+    /// Returns a `RangeError` if the label doesn't name a supported encoding.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder
+    pub fn new( label: &str ) -> Result< TextDecoder, RangeError > {
+        js_try!(
+            return new TextDecoder( @{label} );
+        ).unwrap()
+    }
+
+    /// Returns the canonical name of the decoder's encoding, e.g. `"windows-1252"`
+    /// for a decoder created with the `"latin1"` label.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/encoding)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
+    pub fn encoding( &self ) -> String {
+        js!(
+            return @{self}.encoding;
+        ).try_into().unwrap()
+    }
+
+    /// Decodes the given bytes into a string.
+    ///
+    /// Malformed sequences are replaced with U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode)
+    // https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    pub fn decode( &self, bytes: &[u8] ) -> String {
+        let bytes = unsafe { UnsafeTypedArray::new( bytes ) };
+        js!(
+            return @{self}.decode( @{bytes} );
+        ).try_into().unwrap()
+    }
+}
+
+/// An encoder which converts strings into UTF-8 bytes.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder)
+// https://encoding.spec.whatwg.org/#interface-textencoder
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "TextEncoder")]
+pub struct TextEncoder( Reference );
+
+impl TextEncoder {
+    /// Creates a new `TextEncoder`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/TextEncoder)
+    // https://encoding.spec.whatwg.org/#dom-textencoder
+    pub fn new() -> TextEncoder {
+        js!(
+            return new TextEncoder();
+        ).try_into().unwrap()
+    }
+
+    /// Encodes the given string into UTF-8 bytes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encode)
+    // https://encoding.spec.whatwg.org/#dom-textencoder-encode
+    pub fn encode( &self, text: &str ) -> Vec< u8 > {
+        let array: TypedArray< u8 > = js!(
+            return @{self}.encode( @{text} );
+        ).try_into().unwrap();
+
+        array.into()
+    }
+}
+
+impl Default for TextEncoder {
+    #[inline]
+    fn default() -> Self {
+        TextEncoder::new()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        let encoder = TextEncoder::new();
+        assert_eq!( encoder.encode( "a€" ), vec![ 0x61, 0xE2, 0x82, 0xAC ] );
+        assert_eq!( encoder.encode( "" ), Vec::< u8 >::new() );
+    }
+
+    #[test]
+    fn test_decode() {
+        let decoder = TextDecoder::new( "utf-8" ).unwrap();
+        assert_eq!( decoder.decode( &[ 0x61, 0xE2, 0x82, 0xAC ] ), "a€" );
+        assert_eq!( decoder.decode( &[ 0xFF ] ), "\u{FFFD}" );
+
+        let decoder = TextDecoder::new( "latin1" ).unwrap();
+        assert_eq!( decoder.encoding(), "windows-1252" );
+        assert_eq!( decoder.decode( &[ 0x80, 0xE9 ] ), "€é" );
+
+        assert!( TextDecoder::new( "not-an-encoding" ).is_err() );
+    }
+}
@@ -1,7 +1,9 @@
 use webcore::value::Reference;
+use webcore::try_from::TryInto;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::node::{INode, Node};
 use webapi::slotable::ISlotable;
+use webapi::dom_exception::IndexSizeError;
 
 /// The `TextNode` represents the textual content of an [IElement](trait.IElement.html)
 ///
@@ -20,3 +22,54 @@ pub struct TextNode( Reference );
 impl IEventTarget for TextNode {}
 impl INode for TextNode {}
 impl ISlotable for TextNode {}
+
+impl TextNode {
+    /// Breaks this node into two nodes at the given offset, keeping both in the tree as siblings.
+    /// This node keeps the content up to the offset and the returned node contains the rest.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Text/splitText)
+    // https://dom.spec.whatwg.org/#dom-text-splittext
+    pub fn split_text( &self, offset: u32 ) -> Result< TextNode, IndexSizeError > {
+        js_try!(
+            return @{self}.splitText( @{offset} );
+        ).unwrap()
+    }
+
+    /// Returns the text of this node concatenated with the text of all
+    /// of its logically adjacent text nodes.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Text/wholeText)
+    // https://dom.spec.whatwg.org/#dom-text-wholetext
+    pub fn whole_text( &self ) -> String {
+        js!(
+            return @{self}.wholeText;
+        ).try_into().unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_split_text() {
+        let parent = document().create_element( "div" ).unwrap();
+        let text = document().create_text_node( "hello" );
+        parent.append_child( &text );
+
+        let rest = text.split_text( 2 ).unwrap();
+        assert_eq!( text.text_content().unwrap(), "he" );
+        assert_eq!( rest.text_content().unwrap(), "llo" );
+        assert_eq!( text.next_sibling(), Some( rest.clone().into() ) );
+
+        assert_eq!( text.whole_text(), "hello" );
+        assert_eq!( rest.whole_text(), "hello" );
+    }
+
+    #[test]
+    fn test_split_text_out_of_range() {
+        let text = document().create_text_node( "hello" );
+        assert!( text.split_text( 6 ).is_err() );
+    }
+}
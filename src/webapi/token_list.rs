@@ -1,7 +1,22 @@
 use webcore::value::Reference;
-use webcore::try_from::TryInto;
+use webcore::try_from::{TryFrom, TryInto};
+use webapi::dom_exception::{InvalidCharacterError, SyntaxError};
 use private::TODO;
 
+error_enum_boilerplate! {
+    /// An error returned when a token passed to a `TokenList` is empty (`SyntaxError`)
+    /// or contains whitespace (`InvalidCharacterError`).
+    ///
+    /// The browser validates the emptiness of a token before anything else,
+    /// so an `InvalidCharacterError` alone can't describe every failure.
+    TokenError,
+
+    #[allow(missing_docs)]
+    SyntaxError,
+    #[allow(missing_docs)]
+    InvalidCharacterError
+}
+
 /// The `TokenList` represents a set of space-separated tokens.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList)
@@ -50,4 +65,90 @@ impl TokenList {
     pub fn contains( &self, token: &str ) -> bool {
         js!( return @{self}.contains( @{token} ); ).try_into().unwrap()
     }
+
+    /// Removes token from the underlying string if it's present, or adds it otherwise.
+    ///
+    /// Returns `true` if the token is present after the call.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/toggle)
+    // https://dom.spec.whatwg.org/#ref-for-dom-domtokenlist-toggle
+    pub fn toggle( &self, token: &str ) -> Result< bool, TokenError > {
+        js_try!( return @{self}.toggle( @{token} ); ).unwrap()
+    }
+
+    /// Adds token to the underlying string if `force` is `true`, or removes it otherwise.
+    ///
+    /// Returns `force`, i.e. whether the token is present after the call.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/toggle)
+    // https://dom.spec.whatwg.org/#ref-for-dom-domtokenlist-toggle
+    pub fn toggle_force( &self, token: &str, force: bool ) -> Result< bool, TokenError > {
+        js_try!( return @{self}.toggle( @{token}, @{force} ); ).unwrap()
+    }
+
+    /// Replaces `old_token` with `new_token`, keeping its position in the list.
+    ///
+    /// Returns `false` and leaves the list unchanged if `old_token` isn't present.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/replace)
+    // https://dom.spec.whatwg.org/#ref-for-dom-domtokenlist-replace
+    pub fn replace( &self, old_token: &str, new_token: &str ) -> Result< bool, TokenError > {
+        js_try!(
+            var list = @{self};
+            var old_token = @{old_token};
+            var new_token = @{new_token};
+            var contained = list.contains( old_token );
+            // Older browsers return `undefined` instead of whether the token was replaced.
+            list.replace( old_token, new_token );
+            return contained;
+        ).unwrap()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::element::IElement;
+
+    fn token_list() -> TokenList {
+        document().create_element( "div" ).unwrap().class_list()
+    }
+
+    #[test]
+    fn test_toggle() {
+        let list = token_list();
+        assert_eq!( list.toggle( "foo" ).unwrap(), true );
+        assert!( list.contains( "foo" ) );
+        assert_eq!( list.toggle( "foo" ).unwrap(), false );
+        assert!( !list.contains( "foo" ) );
+
+        assert_eq!( list.toggle_force( "bar", true ).unwrap(), true );
+        assert_eq!( list.toggle_force( "bar", true ).unwrap(), true );
+        assert_eq!( list.toggle_force( "bar", false ).unwrap(), false );
+        assert_eq!( list.len(), 0 );
+
+        match list.toggle( "" ) {
+            Err( TokenError::SyntaxError( _ ) ) => {},
+            result => panic!( "unexpected result: {:?}", result )
+        }
+
+        match list.toggle( "foo bar" ) {
+            Err( TokenError::InvalidCharacterError( _ ) ) => {},
+            result => panic!( "unexpected result: {:?}", result )
+        }
+    }
+
+    #[test]
+    fn test_replace() {
+        let list = token_list();
+        list.add( "foo" ).unwrap();
+        assert_eq!( list.replace( "foo", "bar" ).unwrap(), true );
+        assert!( !list.contains( "foo" ) );
+        assert!( list.contains( "bar" ) );
+
+        assert_eq!( list.replace( "baz", "qux" ).unwrap(), false );
+        assert!( !list.contains( "qux" ) );
+        assert!( list.replace( "bar", "" ).is_err() );
+    }
 }
@@ -9,6 +9,7 @@ pub trait ArrayKind: Sized {
     fn is_typed_array( reference: &Reference ) -> bool;
     fn into_typed_array( slice: &[Self] ) -> TypedArray< Self >;
     fn into_typed_array_from_array_buffer( buffer: &ArrayBuffer ) -> TypedArray< Self >;
+    fn into_typed_array_view( buffer: &ArrayBuffer, byte_offset: u32, length: u32 ) -> TypedArray< Self >;
     fn from_typed_array( array: &TypedArray< Self > ) -> Vec< Self >;
 }
 
@@ -53,6 +54,12 @@ macro_rules! arraykind {
                 reference.downcast().unwrap()
             }
 
+            fn into_typed_array_view( buffer: &ArrayBuffer, byte_offset: u32, length: u32 ) -> TypedArray< Self > {
+                js!(
+                    return new $js_array_type( @{buffer}, @{byte_offset}, @{length} );
+                ).try_into().unwrap()
+            }
+
             fn from_typed_array( array: &TypedArray< Self > ) -> Vec< Self > {
                 let length = array.len() as usize;
                 let mut vector = Vec::with_capacity( length );
@@ -110,6 +117,30 @@ impl< T: ArrayKind > InstanceOf for TypedArray< T > {
 pub struct TypedArray< T: ArrayKind >( Reference, PhantomData< T > );
 
 impl< T: ArrayKind > TypedArray< T > {
+    /// Creates a typed array which is a view of `length` elements of the given buffer,
+    /// starting at `byte_offset`. No data is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte_offset` isn't a multiple of the element size, or if the view
+    /// would extend past the end of the buffer.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray#Syntax)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-typedarray-buffer-byteoffset-length
+    pub fn new_with_buffer( buffer: &ArrayBuffer, byte_offset: u32, length: u32 ) -> Self {
+        let element_size = size_of::< T >() as u64;
+        assert!(
+            byte_offset as u64 % element_size == 0,
+            "byte offset {} is not a multiple of the element size {}", byte_offset, element_size
+        );
+        assert!(
+            byte_offset as u64 + length as u64 * element_size <= buffer.len(),
+            "a view of {} elements at byte offset {} doesn't fit into a buffer of {} bytes", length, byte_offset, buffer.len()
+        );
+
+        T::into_typed_array_view( buffer, byte_offset, length )
+    }
+
     /// Returns the [TypedArray](struct.ArrayBuffer.html) referenced by this typed array.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer)
@@ -156,6 +187,19 @@ mod tests {
     use webcore::try_from::TryInto;
     use webapi::array_buffer::ArrayBuffer;
 
+    #[test]
+    fn into_typed_vec() {
+        let value = js!( return new Float32Array( [ 1.5, 2.5, 3.5 ] ); );
+        assert_eq!( value.clone().into_typed_vec::< f32 >(), Some( vec![ 1.5, 2.5, 3.5 ] ) );
+        assert_eq!( value.into_typed_vec::< f64 >(), None );
+
+        let value = js!( return new Uint8Array( [] ); );
+        assert_eq!( value.into_typed_vec::< u8 >(), Some( vec![] ) );
+
+        let value = js!( return [ 1, 2, 3 ]; );
+        assert_eq!( value.into_typed_vec::< u8 >(), None );
+    }
+
     macro_rules! arraykind_test {
         ($element_type: ident, $js_array_type: ident) => {
             mod $element_type {
@@ -222,6 +266,21 @@ mod tests {
     arraykind_test!(f32, Float32Array);
     arraykind_test!(f64, Float64Array);
 
+    #[test]
+    fn new_with_buffer() {
+        let array_buffer = ArrayBuffer::new( 16 ).unwrap();
+        js!( @(no_return) new Int32Array( @{&array_buffer} )[ 1 ] = 42; );
+
+        let view: TypedArray< i32 > = TypedArray::new_with_buffer( &array_buffer, 4, 2 );
+        assert_eq!( view.len(), 2 );
+        assert_eq!( view.to_vec(), vec![ 42, 0 ] );
+        assert_eq!( view.buffer(), array_buffer );
+
+        js!( @(no_return) @{&view}[ 1 ] = 7; );
+        let whole: TypedArray< i32 > = TypedArray::from( &array_buffer );
+        assert_eq!( whole.to_vec(), vec![ 0, 42, 7, 0 ] );
+    }
+
     fn get_refcount() -> i32 {
         js!( return Object.keys( Module.STDWEB_PRIVATE.id_to_ref_map ).length; ).try_into().unwrap()
     }
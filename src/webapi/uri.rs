@@ -0,0 +1,78 @@
+use webcore::try_from::TryInto;
+use webapi::error::Error;
+
+/// Percent-encodes every character of `component` except `A-Z a-z 0-9 - _ . ! ~ * ' ( )`,
+/// making it safe to embed as a single path segment or query value.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-encodeuricomponent-uricomponent
+pub fn encode_uri_component( component: &str ) -> String {
+    js!(
+        return encodeURIComponent( @{component} );
+    ).try_into().unwrap()
+}
+
+/// Decodes the percent-encoded escape sequences in `component`.
+///
+/// Returns a `URIError` wrapped in an [Error](error/struct.Error.html) if an
+/// escape sequence is malformed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-decodeuricomponent-encodeduricomponent
+pub fn decode_uri_component( component: &str ) -> Result< String, Error > {
+    js_try!(
+        return decodeURIComponent( @{component} );
+    ).unwrap()
+}
+
+/// Percent-encodes a complete URI, leaving the characters which have a
+/// special meaning in URIs (like `/`, `?`, `&` and `#`) intact.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-encodeuri-uri
+pub fn encode_uri( uri: &str ) -> String {
+    js!(
+        return encodeURI( @{uri} );
+    ).try_into().unwrap()
+}
+
+/// Decodes a complete URI previously encoded with [encode_uri](fn.encode_uri.html).
+///
+/// Returns a `URIError` wrapped in an [Error](error/struct.Error.html) if an
+/// escape sequence is malformed.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-decodeuri-encodeduri
+pub fn decode_uri( uri: &str ) -> Result< String, Error > {
+    js_try!(
+        return decodeURI( @{uri} );
+    ).unwrap()
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::error::IError;
+
+    #[test]
+    fn test_uri_component_round_trip() {
+        let text = "a b&c=d/é";
+        let encoded = encode_uri_component( text );
+        assert_eq!( encoded, "a%20b%26c%3Dd%2F%C3%A9" );
+        assert_eq!( decode_uri_component( &encoded ).unwrap(), text );
+    }
+
+    #[test]
+    fn test_uri_round_trip() {
+        let uri = "http://example.com/a b?c=d&e";
+        let encoded = encode_uri( uri );
+        assert_eq!( encoded, "http://example.com/a%20b?c=d&e" );
+        assert_eq!( decode_uri( &encoded ).unwrap(), uri );
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!( decode_uri_component( "%" ).unwrap_err().name(), "URIError" );
+        assert_eq!( decode_uri( "%E0%A4%A" ).unwrap_err().name(), "URIError" );
+    }
+}
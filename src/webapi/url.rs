@@ -0,0 +1,367 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+use webapi::error::TypeError;
+
+/// The `Url` interface parses a URL and gives structured access to its components.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL)
+// https://url.spec.whatwg.org/#url-class
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "URL")]
+pub struct Url( Reference );
+
+macro_rules! url_component {
+    ($(#[$getter_attr:meta])* $getter:ident, $(#[$setter_attr:meta])* $setter:ident, $name:tt) => {
+        $(#[$getter_attr])*
+        pub fn $getter( &self ) -> String {
+            js!(
+                return @{self}.$name;
+            ).try_into().unwrap()
+        }
+
+        $(#[$setter_attr])*
+        pub fn $setter( &self, value: &str ) {
+            js! { @(no_return)
+                @{self}.$name = @{value};
+            }
+        }
+    }
+}
+
+impl Url {
+    /// Parses an absolute URL.
+    ///
+    /// Fails with a `TypeError` if `input` isn't a valid absolute URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+    // https://url.spec.whatwg.org/#dom-url-url
+    pub fn new( input: &str ) -> Result< Self, TypeError > {
+        js_try!(
+            return new URL( @{input} );
+        ).unwrap()
+    }
+
+    /// Parses `input` relative to the given `base` URL.
+    ///
+    /// Fails with a `TypeError` if `base` isn't a valid absolute URL,
+    /// or if `input` can't be resolved against it.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/URL)
+    // https://url.spec.whatwg.org/#dom-url-url
+    pub fn new_with_base( input: &str, base: &str ) -> Result< Self, TypeError > {
+        js_try!(
+            return new URL( @{input}, @{base} );
+        ).unwrap()
+    }
+
+    /// Returns the whole serialized URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/href)
+    // https://url.spec.whatwg.org/#dom-url-href
+    pub fn href( &self ) -> String {
+        js!(
+            return @{self}.href;
+        ).try_into().unwrap()
+    }
+
+    /// Replaces the whole URL.
+    ///
+    /// Fails with a `TypeError` if `href` isn't a valid absolute URL,
+    /// in which case the URL is left unchanged.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/href)
+    // https://url.spec.whatwg.org/#dom-url-href
+    pub fn set_href( &self, href: &str ) -> Result< (), TypeError > {
+        js_try!( @(no_return)
+            @{self}.href = @{href};
+        ).unwrap()
+    }
+
+    url_component! {
+        /// Returns the scheme of the URL, including the trailing `:`, e.g. `"https:"`.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/protocol)
+        // https://url.spec.whatwg.org/#dom-url-protocol
+        protocol,
+        /// Sets the scheme of the URL. Invalid schemes are ignored.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/protocol)
+        // https://url.spec.whatwg.org/#dom-url-protocol
+        set_protocol,
+        protocol
+    }
+
+    url_component! {
+        /// Returns the host of the URL, including the port if it's not the default one.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/host)
+        // https://url.spec.whatwg.org/#dom-url-host
+        host,
+        /// Sets the host of the URL, and the port if one is given.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/host)
+        // https://url.spec.whatwg.org/#dom-url-host
+        set_host,
+        host
+    }
+
+    url_component! {
+        /// Returns the host of the URL without the port.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hostname)
+        // https://url.spec.whatwg.org/#dom-url-hostname
+        hostname,
+        /// Sets the host of the URL, keeping its port.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hostname)
+        // https://url.spec.whatwg.org/#dom-url-hostname
+        set_hostname,
+        hostname
+    }
+
+    url_component! {
+        /// Returns the path of the URL, starting with a `/`.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/pathname)
+        // https://url.spec.whatwg.org/#dom-url-pathname
+        pathname,
+        /// Sets the path of the URL.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/pathname)
+        // https://url.spec.whatwg.org/#dom-url-pathname
+        set_pathname,
+        pathname
+    }
+
+    url_component! {
+        /// Returns the query string of the URL, including the leading `?`,
+        /// or an empty string if there is no query.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/search)
+        // https://url.spec.whatwg.org/#dom-url-search
+        search,
+        /// Sets the query string of the URL. A leading `?` is optional.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/search)
+        // https://url.spec.whatwg.org/#dom-url-search
+        set_search,
+        search
+    }
+
+    url_component! {
+        /// Returns the fragment of the URL, including the leading `#`,
+        /// or an empty string if there is no fragment.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hash)
+        // https://url.spec.whatwg.org/#dom-url-hash
+        hash,
+        /// Sets the fragment of the URL. A leading `#` is optional.
+        ///
+        /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/hash)
+        // https://url.spec.whatwg.org/#dom-url-hash
+        set_hash,
+        hash
+    }
+
+    /// Returns the query string of the URL as search parameters.
+    ///
+    /// The returned object is live: modifying it updates the URL.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URL/searchParams)
+    // https://url.spec.whatwg.org/#dom-url-searchparams
+    pub fn search_params( &self ) -> UrlSearchParams {
+        js!(
+            return @{self}.searchParams;
+        ).try_into().unwrap()
+    }
+}
+
+/// The `UrlSearchParams` interface defines utility methods to work with
+/// the query string of a URL.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams)
+// https://url.spec.whatwg.org/#urlsearchparams
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "URLSearchParams")]
+pub struct UrlSearchParams( Reference );
+
+impl UrlSearchParams {
+    /// Parses the given query string. A leading `?` is ignored.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/URLSearchParams)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-urlsearchparams
+    pub fn new( query: &str ) -> Self {
+        js!(
+            return new URLSearchParams( @{query} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns the first value associated with the given search parameter,
+    /// or `None` if there is no such parameter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/get)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-get
+    pub fn get( &self, name: &str ) -> Option< String > {
+        js!(
+            return @{self}.get( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns all of the values associated with the given search parameter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/getAll)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-getall
+    pub fn get_all( &self, name: &str ) -> Vec< String > {
+        js!(
+            return @{self}.getAll( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Returns `true` if a parameter with the given name exists.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/has)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-has
+    pub fn has( &self, name: &str ) -> bool {
+        js!(
+            return @{self}.has( @{name} );
+        ).try_into().unwrap()
+    }
+
+    /// Appends a new value to the given search parameter, keeping its existing values.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/append)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-append
+    pub fn append( &self, name: &str, value: &str ) {
+        js! { @(no_return)
+            @{self}.append( @{name}, @{value} );
+        }
+    }
+
+    /// Sets the value of the given search parameter, removing any other values it has.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/set)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-set
+    pub fn set( &self, name: &str, value: &str ) {
+        js! { @(no_return)
+            @{self}.set( @{name}, @{value} );
+        }
+    }
+
+    /// Removes all of the values of the given search parameter.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/delete)
+    // https://url.spec.whatwg.org/#dom-urlsearchparams-delete
+    pub fn delete( &self, name: &str ) {
+        js! { @(no_return)
+            @{self}.delete( @{name} );
+        }
+    }
+
+    /// Returns an iterator over the `(name, value)` pairs, in the order they appear
+    /// in the query string.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams/entries)
+    // https://url.spec.whatwg.org/#interface-urlsearchparams
+    pub fn iter( &self ) -> UrlSearchParamsIter {
+        UrlSearchParamsIter {
+            iter: js!( return @{self}.entries(); )
+        }
+    }
+}
+
+impl< 'a > IntoIterator for &'a UrlSearchParams {
+    type Item = (String, String);
+    type IntoIter = UrlSearchParamsIter;
+
+    #[inline]
+    fn into_iter( self ) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the name-value pairs of [UrlSearchParams](struct.UrlSearchParams.html).
+///
+/// This is created by the [UrlSearchParams::iter](struct.UrlSearchParams.html#method.iter) method.
+#[derive(Debug)]
+pub struct UrlSearchParamsIter {
+    iter: Value
+}
+
+impl Iterator for UrlSearchParamsIter {
+    type Item = (String, String);
+    fn next( &mut self ) -> Option< Self::Item > {
+        let entry = js!( return @{&self.iter}.next(); );
+        let is_done: bool = js!( return @{&entry}.done; ).try_into().unwrap();
+        if is_done {
+            return None;
+        }
+
+        let name = js!( return @{&entry}.value[0]; ).try_into().unwrap();
+        let value = js!( return @{&entry}.value[1]; ).try_into().unwrap();
+        Some( (name, value) )
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_search_params() {
+        let params = UrlSearchParams::new( "?a=1&b=2&b=3" );
+        assert_eq!( params.get( "a" ), Some( "1".to_owned() ) );
+        assert_eq!( params.get( "b" ), Some( "2".to_owned() ) );
+        assert_eq!( params.get_all( "b" ), vec![ "2".to_owned(), "3".to_owned() ] );
+        assert_eq!( params.get( "c" ), None );
+        assert!( params.has( "a" ) );
+        assert!( !params.has( "c" ) );
+    }
+
+    #[test]
+    fn test_url_search_params_mutation() {
+        let params = UrlSearchParams::new( "a=1" );
+        params.append( "b", "2" );
+        params.append( "b", "3" );
+        params.set( "a", "4" );
+        assert_eq!( params.iter().collect::< Vec< _ > >(), vec![
+            ("a".to_owned(), "4".to_owned()),
+            ("b".to_owned(), "2".to_owned()),
+            ("b".to_owned(), "3".to_owned())
+        ]);
+
+        params.delete( "b" );
+        assert!( !params.has( "b" ) );
+        assert_eq!( (&params).into_iter().count(), 1 );
+    }
+
+    #[test]
+    fn test_url() {
+        let url = Url::new( "https://example.com:8080/todos/list?filter=active#top" ).unwrap();
+        assert_eq!( url.protocol(), "https:" );
+        assert_eq!( url.host(), "example.com:8080" );
+        assert_eq!( url.hostname(), "example.com" );
+        assert_eq!( url.pathname(), "/todos/list" );
+        assert_eq!( url.search(), "?filter=active" );
+        assert_eq!( url.hash(), "#top" );
+        assert_eq!( url.search_params().get( "filter" ), Some( "active".to_owned() ) );
+
+        url.set_hash( "/completed" );
+        url.set_pathname( "/todos" );
+        url.search_params().set( "filter", "completed" );
+        assert_eq!( url.href(), "https://example.com:8080/todos?filter=completed#/completed" );
+
+        assert!( url.set_href( "not a url" ).is_err() );
+        assert_eq!( url.hostname(), "example.com" );
+        url.set_href( "http://localhost/" ).unwrap();
+        assert_eq!( url.host(), "localhost" );
+    }
+
+    #[test]
+    fn test_url_with_base() {
+        let url = Url::new_with_base( "../b?c=1", "https://example.com/a/x/" ).unwrap();
+        assert_eq!( url.href(), "https://example.com/a/b?c=1" );
+
+        assert!( Url::new( "/relative" ).is_err() );
+        assert!( Url::new_with_base( "/relative", "also relative" ).is_err() );
+    }
+}
@@ -0,0 +1,167 @@
+use webcore::value::{Reference, Value};
+use webcore::try_from::TryInto;
+
+/// The `WeakMap` object is a collection of key/value pairs in which the keys are weakly referenced.
+/// The keys must be objects and the values can be arbitrary values.
+///
+/// Since the keys are held weakly, an entry doesn't keep its key alive; this makes it
+/// possible to associate data with e.g. DOM nodes without leaking them.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "WeakMap")]
+pub struct WeakMap( Reference );
+
+impl WeakMap {
+    /// Creates a new, empty `WeakMap`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap-constructor
+    pub fn new() -> Self {
+        js!(
+            return new WeakMap();
+        ).try_into().unwrap()
+    }
+
+    /// Returns the value associated with the `key`, or `Value::Undefined` if there is none.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap.prototype.get
+    pub fn get( &self, key: &Reference ) -> Value {
+        js!(
+            return @{self}.get( @{key} );
+        )
+    }
+
+    /// Sets the value for the `key`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap.prototype.set
+    pub fn set( &self, key: &Reference, value: Value ) {
+        js! { @(no_return)
+            @{self}.set( @{key}, @{value} );
+        }
+    }
+
+    /// Returns whether a value has been associated with the `key`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap.prototype.has
+    pub fn has( &self, key: &Reference ) -> bool {
+        js!(
+            return @{self}.has( @{key} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes the value associated with the `key`. Returns `true` if there was such a value.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakmap.prototype.delete
+    pub fn delete( &self, key: &Reference ) -> bool {
+        js!(
+            return @{self}.delete( @{key} );
+        ).try_into().unwrap()
+    }
+}
+
+impl Default for WeakMap {
+    #[inline]
+    fn default() -> Self {
+        WeakMap::new()
+    }
+}
+
+/// The `WeakSet` object lets you store weakly held objects in a collection.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet)
+// https://www.ecma-international.org/ecma-262/6.0/#sec-weakset-objects
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "WeakSet")]
+pub struct WeakSet( Reference );
+
+impl WeakSet {
+    /// Creates a new, empty `WeakSet`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakset-constructor
+    pub fn new() -> Self {
+        js!(
+            return new WeakSet();
+        ).try_into().unwrap()
+    }
+
+    /// Adds the `value` to the set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakset.prototype.add
+    pub fn add( &self, value: &Reference ) {
+        js! { @(no_return)
+            @{self}.add( @{value} );
+        }
+    }
+
+    /// Returns whether the `value` is in the set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakset.prototype.has
+    pub fn has( &self, value: &Reference ) -> bool {
+        js!(
+            return @{self}.has( @{value} );
+        ).try_into().unwrap()
+    }
+
+    /// Removes the `value` from the set. Returns `true` if it was in the set.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete)
+    // https://www.ecma-international.org/ecma-262/6.0/#sec-weakset.prototype.delete
+    pub fn delete( &self, value: &Reference ) -> bool {
+        js!(
+            return @{self}.delete( @{value} );
+        ).try_into().unwrap()
+    }
+}
+
+impl Default for WeakSet {
+    #[inline]
+    fn default() -> Self {
+        WeakSet::new()
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+
+    #[test]
+    fn test_weak_map() {
+        let map = WeakMap::new();
+        let element = document().create_element( "div" ).unwrap();
+        let key: &Reference = element.as_ref();
+
+        assert!( !map.has( key ) );
+        assert_eq!( map.get( key ), Value::Undefined );
+
+        map.set( key, "foo".into() );
+        assert!( map.has( key ) );
+        assert_eq!( map.get( key ), "foo" );
+
+        assert!( map.delete( key ) );
+        assert!( !map.has( key ) );
+        assert!( !map.delete( key ) );
+    }
+
+    #[test]
+    fn test_weak_set() {
+        let set = WeakSet::new();
+        let element = document().create_element( "div" ).unwrap();
+        let value: &Reference = element.as_ref();
+
+        assert!( !set.has( value ) );
+        set.add( value );
+        assert!( set.has( value ) );
+        assert!( set.delete( value ) );
+        assert!( !set.has( value ) );
+    }
+}
@@ -4,7 +4,7 @@ use webcore::unsafe_typed_array::UnsafeTypedArray;
 use webapi::event_target::{IEventTarget, EventTarget};
 use webapi::blob::Blob;
 use webapi::array_buffer::ArrayBuffer;
-use webapi::dom_exception::{InvalidAccessError, SecurityError, SyntaxError};
+use webapi::dom_exception::{InvalidAccessError, InvalidStateError, SecurityError, SyntaxError};
 use private::TODO;
 
 /// Wrapper type around a CloseEvent code, indicating why the WebSocket was closed
@@ -250,20 +250,22 @@ impl WebSocket {
     /// connection, increasing the value of bufferedAmount by the number of bytes needed
     /// to contain the data. If the data can't be sent (for example, because it needs to
     /// be buffered but the buffer is full), the socket is closed automatically.
+    ///
+    /// Returns an `InvalidStateError` if the socket is still connecting.
     // https://html.spec.whatwg.org/#the-websocket-interface:dom-websocket-send
-    pub fn send_blob(&self, blob: &Blob) -> Result< (), TODO > {
-        js!( @(no_return) @{self}.send(@{blob}); );
-        Ok(())
+    pub fn send_blob(&self, blob: &Blob) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return) @{self}.send(@{blob}); ).unwrap()
     }
 
     /// Enqueues the specified data to be transmitted to the server over the WebSocket
     /// connection, increasing the value of bufferedAmount by the number of bytes needed
     /// to contain the data. If the data can't be sent (for example, because it needs to
     /// be buffered but the buffer is full), the socket is closed automatically.
+    ///
+    /// Returns an `InvalidStateError` if the socket is still connecting.
     // https://html.spec.whatwg.org/#the-websocket-interface:dom-websocket-send
-    pub fn send_array_buffer(&self, array_buffer: &ArrayBuffer) -> Result< (), TODO > {
-        js!( @(no_return) @{self}.send(@{array_buffer}); );
-        Ok(())
+    pub fn send_array_buffer(&self, array_buffer: &ArrayBuffer) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return) @{self}.send(@{array_buffer}); ).unwrap()
     }
 
     /// Enqueues the specified data to be transmitted to the server over the WebSocket
@@ -316,6 +318,31 @@ mod web_tests {
         }
     }
 
+    #[test]
+    fn test_connection_info_before_open() {
+        let socket = WebSocket::new("ws://localhost").unwrap();
+
+        assert_eq!(socket.buffered_amount(), 0);
+        assert_eq!(socket.protocol(), "");
+        assert_eq!(socket.extensions(), "");
+
+        socket.close();
+    }
+
+    #[test]
+    fn test_send_before_open() {
+        let socket = WebSocket::new("ws://localhost").unwrap();
+        assert_eq!(socket.ready_state(), SocketReadyState::Connecting);
+
+        let result: Result<(), InvalidStateError> = socket.send_array_buffer(&ArrayBuffer::new(4).unwrap());
+        assert!(result.is_err());
+
+        let result: Result<(), InvalidStateError> = socket.send_blob(&Blob::new());
+        assert!(result.is_err());
+
+        socket.close();
+    }
+
     #[test]
     fn test_close() {
         let socket = WebSocket::new("ws://localhost").unwrap();
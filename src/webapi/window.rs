@@ -5,11 +5,27 @@ use webapi::window_or_worker::IWindowOrWorker;
 use webapi::storage::Storage;
 use webapi::location::Location;
 use webapi::history::History;
+use webapi::performance::Performance;
+use webapi::navigator::Navigator;
+use webapi::screen::Screen;
 use webapi::selection::Selection;
+use webapi::media_query_list::MediaQueryList;
+use webapi::element::IElement;
+use webapi::html_element::{ScrollBehavior, scroll_behavior_to_str};
+use webapi::css_style_declaration::CssStyleDeclaration;
 use webcore::once::Once;
 use webcore::value::Value;
+use discard::Discard;
 
 /// A handle to a pending animation frame request.
+///
+/// Dropping the handle does **not** cancel the request; the callback will still be
+/// called on the next frame. To cancel it either call [cancel](#method.cancel) or
+/// [Window::cancel_animation_frame](struct.Window.html#method.cancel_animation_frame),
+/// or wrap the handle in a [DiscardOnDrop](../struct.DiscardOnDrop.html) to have it
+/// cancelled automatically when dropped.
+///
+/// Cancelling a request whose callback was already called does nothing.
 #[derive(Debug)]
 pub struct RequestAnimationFrameHandle(Value);
 
@@ -26,6 +42,71 @@ impl RequestAnimationFrameHandle {
     }
 }
 
+impl Discard for RequestAnimationFrameHandle {
+    #[inline]
+    fn discard( self ) {
+        self.cancel();
+    }
+}
+
+/// A handle to a pending idle callback request.
+///
+/// The request is cancelled when the handle is dropped, so it must be kept
+/// alive until the callback has been called.
+#[must_use = "the idle callback is cancelled when the handle is dropped"]
+#[derive(Debug)]
+pub struct IdleCallbackHandle(Value);
+
+impl IdleCallbackHandle {
+    /// Cancels an idle callback request.
+    ///
+    /// This is equivalent to dropping the handle.
+    ///
+    /// [(Javascript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/cancelIdleCallback)
+    pub fn cancel( self ) {}
+}
+
+impl Drop for IdleCallbackHandle {
+    fn drop( &mut self ) {
+        js! { @(no_return)
+            var val = @{&self.0};
+            val.window.cancelIdleCallback(val.request);
+            val.callback.drop();
+        }
+    }
+}
+
+/// Information passed to an idle callback requested with
+/// [Window::request_idle_callback](struct.Window.html#method.request_idle_callback).
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IdleDeadline)
+// https://w3c.github.io/requestidlecallback/#the-idledeadline-interface
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "IdleDeadline")]
+pub struct IdleDeadline( Reference );
+
+impl IdleDeadline {
+    /// Returns the estimated number of milliseconds remaining in the current idle period.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IdleDeadline/timeRemaining)
+    // https://w3c.github.io/requestidlecallback/#the-timeremaining-method
+    pub fn time_remaining( &self ) -> f64 {
+        js!(
+            return @{self}.timeRemaining();
+        ).try_into().unwrap()
+    }
+
+    /// Returns whether the callback is being called because its timeout expired.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/IdleDeadline/didTimeout)
+    // https://w3c.github.io/requestidlecallback/#the-didtimeout-attribute
+    pub fn did_timeout( &self ) -> bool {
+        js!(
+            return @{self}.didTimeout;
+        ).try_into().unwrap()
+    }
+}
+
 /// The `Window` object represents a window containing a DOM document.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window)
@@ -144,6 +225,37 @@ impl Window {
         RequestAnimationFrameHandle(values)
     }
 
+    /// Cancels an animation frame request previously made with
+    /// [request_animation_frame](#method.request_animation_frame).
+    ///
+    /// This is equivalent to calling [RequestAnimationFrameHandle::cancel](struct.RequestAnimationFrameHandle.html#method.cancel).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/cancelAnimationFrame)
+    // https://html.spec.whatwg.org/#animation-frames:dom-animationframeprovider-cancelanimationframe
+    pub fn cancel_animation_frame( &self, handle: RequestAnimationFrameHandle ) {
+        handle.cancel();
+    }
+
+    /// Queues a function to be called during a browser's idle period, which allows
+    /// background and low priority work to be done without affecting latency-critical
+    /// events like animation and input response.
+    ///
+    /// The callback is passed an [IdleDeadline](struct.IdleDeadline.html) which tells
+    /// how much time is left in the current idle period.
+    ///
+    /// The request is cancelled when the returned handle is dropped.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback)
+    // https://w3c.github.io/requestidlecallback/#the-requestidlecallback-method
+    pub fn request_idle_callback< F: FnOnce(IdleDeadline) + 'static >( &self, callback: F ) -> IdleCallbackHandle {
+        let values: Value = js!{
+            var callback = @{Once(callback)};
+            var request = @{self}.requestIdleCallback(callback);
+            return { request: request, callback: callback, window: @{self} };
+        };
+        IdleCallbackHandle(values)
+    }
+
     /// Returns the global [History](struct.History.html) object, which provides methods to
     /// manipulate the browser history.
     ///
@@ -157,6 +269,44 @@ impl Window {
         }
     }
 
+    /// Returns the [Performance](struct.Performance.html) object of the window, which
+    /// provides high resolution timing for the current page.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/performance)
+    // https://w3c.github.io/hr-time/#the-performance-attribute
+    pub fn performance( &self ) -> Performance {
+        unsafe {
+            js!(
+                return @{self}.performance;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns the [Navigator](struct.Navigator.html) object of the window, which
+    /// describes the state and the identity of the user agent.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/navigator)
+    // https://html.spec.whatwg.org/#dom-navigator
+    pub fn navigator( &self ) -> Navigator {
+        unsafe {
+            js!(
+                return @{self}.navigator;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
+    /// Returns the [Screen](struct.Screen.html) on which the window is being rendered.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/screen)
+    // https://drafts.csswg.org/cssom-view/#dom-window-screen
+    pub fn screen( &self ) -> Screen {
+        unsafe {
+            js!(
+                return @{self}.screen;
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+
     /// Returns the width (in pixels) of the browser window viewport including, if rendered,
     /// the vertical scrollbar.
     ///
@@ -226,6 +376,63 @@ impl Window {
         ).try_into().unwrap()
     }
 
+    /// Returns the number of pixels the document is currently scrolled along the horizontal axis.
+    ///
+    /// This is the same as [page_x_offset](#method.page_x_offset).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollX)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollx
+    pub fn scroll_x(&self) -> f64 {
+        self.page_x_offset()
+    }
+
+    /// Returns the number of pixels the document is currently scrolled along the vertical axis.
+    ///
+    /// This is the same as [page_y_offset](#method.page_y_offset).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollY)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrolly
+    pub fn scroll_y(&self) -> f64 {
+        self.page_y_offset()
+    }
+
+    /// Scrolls the document to the given coordinates.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollTo)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollto
+    pub fn scroll_to(&self, x: f64, y: f64) {
+        self.scroll_to_with_behavior(x, y, ScrollBehavior::Auto)
+    }
+
+    /// Scrolls the document to the given coordinates using the given behavior,
+    /// e.g. `ScrollBehavior::Smooth` for an animated scroll.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollTo)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollto
+    pub fn scroll_to_with_behavior(&self, x: f64, y: f64, behavior: ScrollBehavior) {
+        js! { @(no_return)
+            @{self}.scrollTo({ left: @{x}, top: @{y}, behavior: @{scroll_behavior_to_str(behavior)} });
+        }
+    }
+
+    /// Scrolls the document by the given amounts.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollBy)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollby
+    pub fn scroll_by(&self, dx: f64, dy: f64) {
+        self.scroll_by_with_behavior(dx, dy, ScrollBehavior::Auto)
+    }
+
+    /// Scrolls the document by the given amounts using the given behavior.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollBy)
+    // https://drafts.csswg.org/cssom-view/#dom-window-scrollby
+    pub fn scroll_by_with_behavior(&self, dx: f64, dy: f64, behavior: ScrollBehavior) {
+        js! { @(no_return)
+            @{self}.scrollBy({ left: @{dx}, top: @{dy}, behavior: @{scroll_behavior_to_str(behavior)} });
+        }
+    }
+
     /// The ratio in resolution from physical pixels to CSS pixels
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio)
@@ -247,4 +454,88 @@ impl Window {
             ).into_reference_unchecked()
         }
     }
+
+    /// Returns the resolved values of all of the CSS properties of the element,
+    /// after applying the active stylesheets and resolving any layout.
+    ///
+    /// `pseudo` selects a pseudo-element, e.g. `Some("::before")`. The returned
+    /// declaration is read-only, and live: it reflects later changes to the element's style.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/getComputedStyle)
+    // https://drafts.csswg.org/cssom/#dom-window-getcomputedstyle
+    pub fn get_computed_style< T: IElement >(&self, element: &T, pseudo: Option<&str>) -> CssStyleDeclaration {
+        js!(
+            return @{self}.getComputedStyle(@{element.as_ref()}, @{pseudo});
+        ).try_into().unwrap()
+    }
+
+    /// Returns a [MediaQueryList](struct.MediaQueryList.html) object representing
+    /// the results of the specified media query string.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/Window/matchMedia)
+    // https://drafts.csswg.org/cssom-view/#dom-window-matchmedia
+    pub fn match_media(&self, query: &str) -> MediaQueryList {
+        unsafe {
+            js!(
+                return @{self}.matchMedia(@{query});
+            ).into_reference_unchecked().unwrap()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    use webapi::document::document;
+    use webapi::node::INode;
+
+    #[test]
+    fn test_dimensions() {
+        let window = window();
+        // Zooming out can push the ratio below 1.0, so only check that it's sane.
+        assert!(window.device_pixel_ratio() > 0.0);
+        assert!(window.inner_width() > 0);
+        assert!(window.inner_height() > 0);
+        assert!(window.outer_width() >= 0);
+        assert!(window.outer_height() >= 0);
+    }
+
+    #[test]
+    fn test_scroll() {
+        let spacer = document().create_element("div").unwrap();
+        spacer.set_attribute("style", "width: 10px; height: 10000px").unwrap();
+        document().body().unwrap().append_child(&spacer);
+
+        let window = window();
+        window.scroll_to(0.0, 100.0);
+        assert_eq!(window.scroll_y(), 100.0);
+        assert_eq!(window.scroll_x(), 0.0);
+
+        window.scroll_by(0.0, -40.0);
+        assert_eq!(window.scroll_y(), 60.0);
+        assert_eq!(window.page_y_offset(), window.scroll_y());
+
+        window.scroll_to_with_behavior(0.0, 0.0, ScrollBehavior::Instant);
+        assert_eq!(window.scroll_y(), 0.0);
+
+        document().body().unwrap().remove_child(&spacer).unwrap();
+    }
+
+    #[test]
+    fn test_request_idle_callback_cancel() {
+        let handle = window().request_idle_callback(|_| panic!("cancelled idle callback was called"));
+        handle.cancel();
+    }
+
+    #[test]
+    fn test_cancel_animation_frame() {
+        use webcore::discard::DiscardOnDrop;
+
+        let window = window();
+        let handle = window.request_animation_frame(|_| panic!("cancelled animation frame was called"));
+        window.cancel_animation_frame(handle);
+
+        let handle = DiscardOnDrop::new(window.request_animation_frame(|_| panic!("discarded animation frame was called")));
+        drop(handle);
+    }
 }
@@ -1,4 +1,5 @@
 use webapi::event_target::{IEventTarget, EventTarget};
+use webapi::form_data::FormData;
 use webapi::dom_exception::{InvalidAccessError, InvalidStateError};
 use webcore::unsafe_typed_array::UnsafeTypedArray;
 use webcore::value::{
@@ -20,6 +21,20 @@ use private::TODO;
 #[reference(subclass_of(EventTarget))]
 pub struct XmlHttpRequest( Reference );
 
+/// The upload process of an [XmlHttpRequest](struct.XmlHttpRequest.html).
+///
+/// Attach progress event listeners (e.g. [ProgressEvent](event/struct.ProgressEvent.html) or
+/// [LoadEndEvent](event/struct.LoadEndEvent.html)) to it to track the progress of the request body.
+///
+/// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/upload)
+// https://xhr.spec.whatwg.org/#xmlhttprequestupload
+#[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
+#[reference(instance_of = "XMLHttpRequestUpload")]
+#[reference(subclass_of(EventTarget))]
+pub struct XmlHttpRequestUpload( Reference );
+
+impl IEventTarget for XmlHttpRequestUpload {}
+
 /// An enum indicating the state of the `XmlHttpRequest`.
 ///
 /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/readyState)
@@ -158,6 +173,37 @@ impl XmlHttpRequest {
         js!(return @{self}.status;).try_into().unwrap()
     }
 
+    /// Returns the object which represents the upload process of this request.
+    ///
+    /// Event listeners for the upload have to be added before calling [send](#method.send).
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/upload)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-upload
+    pub fn upload(&self) -> XmlHttpRequestUpload {
+        js!(return @{self}.upload;).try_into().unwrap()
+    }
+
+    /// Returns the number of milliseconds a request can take before automatically being
+    /// terminated. A value of 0 (the default) means there is no timeout.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/timeout)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-timeout
+    pub fn timeout(&self) -> u32 {
+        js!(return @{self}.timeout;).try_into().unwrap()
+    }
+
+    /// Sets the number of milliseconds a request can take before automatically being
+    /// terminated, after which a [TimeoutEvent](event/struct.TimeoutEvent.html) is dispatched.
+    /// A value of 0 means there is no timeout.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/timeout)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-timeout
+    pub fn set_timeout(&self, ms: u32) {
+        js! { @(no_return)
+            @{self}.timeout = @{ms};
+        }
+    }
+
     /// Open connection with given method (ie GET or POST), and the url to hit.
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/open)
@@ -185,6 +231,24 @@ impl XmlHttpRequest {
         }
     }
 
+    /// Returns all of the response headers separated by CRLF, or an empty string
+    /// if no response has been received yet.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/getAllResponseHeaders)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-getallresponseheaders
+    pub fn get_all_response_headers(&self) -> String {
+        js!(return @{self}.getAllResponseHeaders();).try_into().unwrap()
+    }
+
+    /// Returns the serialized URL of the response, after any redirects, or an
+    /// empty string if the URL is null.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/responseURL)
+    // https://xhr.spec.whatwg.org/#dom-xmlhttprequest-responseurl
+    pub fn response_url(&self) -> String {
+        js!(return @{self}.responseURL;).try_into().unwrap()
+    }
+
     /// Sets the value of an HTTP request header. Must be called after `open()`,
     /// but before `send()`. If this method is called several times with the same
     /// header, the values are merged into one single request header.
@@ -199,6 +263,24 @@ impl XmlHttpRequest {
         Ok(())
     }
 
+    /// Sets the `Accept` request header, telling the server which type of
+    /// response the client expects. Must be called after `open()`, but before `send()`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept)
+    // https://tools.ietf.org/html/rfc7231#section-5.3.2
+    pub fn accept(&self, mime: &str) -> Result< (), TODO > {
+        self.set_request_header("Accept", mime)
+    }
+
+    /// Sets the `Accept` request header to `application/json`. Must be called
+    /// after `open()`, but before `send()`.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept)
+    // https://tools.ietf.org/html/rfc7231#section-5.3.2
+    pub fn accept_json(&self) -> Result< (), TODO > {
+        self.accept("application/json")
+    }
+
     /// Send request on an open connection with no data
     ///
     /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
@@ -235,6 +317,21 @@ impl XmlHttpRequest {
         Ok(())
     }
 
+    /// Send request on an open connection with a `multipart/form-data` body.
+    ///
+    /// The `Content-Type` header, including the multipart boundary, is set
+    /// by the browser, so it shouldn't be set manually.
+    ///
+    /// Returns an `InvalidStateError` if the request isn't open or was already sent.
+    ///
+    /// [(JavaScript docs)](https://developer.mozilla.org/en-US/docs/Web/API/XMLHttpRequest/send)
+    // https://xhr.spec.whatwg.org/#ref-for-dom-xmlhttprequest-send
+    pub fn send_form_data(&self, data: &FormData) -> Result< (), InvalidStateError > {
+        js_try!( @(no_return)
+            @{self}.send(@{data});
+        ).unwrap()
+    }
+
     /// Aborts the request if it has already been sent.
     /// When a request is aborted, its [ready_state](struct.XmlHttpRequest.html#method.ready_state) is changed to [Done](enum.XhrReadyState.html#variant.Done)
     /// and the [status](struct.XmlHttpRequest.html#method.status) code is set to
@@ -248,3 +345,125 @@ impl XmlHttpRequest {
         };
     }
 }
+
+#[cfg(all(test, feature = "web_test"))]
+mod tests {
+    use super::*;
+    #[cfg(rust_nightly)]
+    use async_test;
+
+    // Served by `ci/echo_server.js`.
+    #[cfg(rust_nightly)]
+    const ECHO_URL: &str = "http://localhost:8765/echo";
+
+    #[test]
+    fn test_upload() {
+        use webapi::events::progress::ProgressEvent;
+
+        let xhr = XmlHttpRequest::new();
+        let upload = xhr.upload();
+        assert_eq!(xhr.upload(), upload);
+
+        let handle = upload.add_event_listener(|_: ProgressEvent| {});
+        handle.remove();
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_upload_progress<F: FnOnce()>(done: F) {
+        use webapi::events::progress::{IProgressEvent, ProgressEvent};
+
+        let xhr = XmlHttpRequest::new();
+        xhr.open("POST", ECHO_URL).unwrap();
+
+        let mut done = Some(done);
+        xhr.upload().add_event_listener(move |event: ProgressEvent| {
+            assert_eq!(event.total(), 5);
+            if let Some(done) = done.take() {
+                done();
+            }
+        });
+
+        xhr.send_with_string("hello").unwrap();
+    }
+
+    #[test]
+    fn test_response_headers_before_send() {
+        let xhr = XmlHttpRequest::new();
+        assert_eq!(xhr.get_all_response_headers(), "");
+        assert_eq!(xhr.response_url(), "");
+        assert_eq!(xhr.get_response_header("Content-Type"), None);
+    }
+
+    #[test]
+    fn test_send_form_data_invalid_state() {
+        let data = FormData::new();
+        data.append_string("name", "value");
+
+        let xhr = XmlHttpRequest::new();
+        assert!(xhr.send_form_data(&data).is_err());
+
+        xhr.open("POST", "/").unwrap();
+        xhr.send_form_data(&data).unwrap();
+        assert!(xhr.send_form_data(&data).is_err());
+        xhr.abort();
+    }
+
+    #[cfg(rust_nightly)]
+    #[async_test]
+    fn test_send_form_data<F: FnOnce()>(done: F) {
+        use webapi::events::progress::ProgressLoadEvent;
+
+        let data = FormData::new();
+        data.append_string("name", "value");
+
+        let xhr = XmlHttpRequest::new();
+        xhr.open("POST", ECHO_URL).unwrap();
+
+        let mut done = Some(done);
+        xhr.add_event_listener({
+            let xhr = xhr.clone();
+            move |_: ProgressLoadEvent| {
+                let content_type: String = js!(
+                    return JSON.parse(@{&xhr}.responseText).headers["content-type"];
+                ).try_into().unwrap();
+                assert!(content_type.starts_with("multipart/form-data; boundary="), "unexpected Content-Type: {}", content_type);
+
+                if let Some(done) = done.take() {
+                    done();
+                }
+            }
+        });
+
+        xhr.send_form_data(&data).unwrap();
+    }
+
+    #[test]
+    fn test_accept_json() {
+        let xhr = XmlHttpRequest::new();
+        let headers: Value = js!(
+            var xhr = @{&xhr};
+            var headers = {};
+            var set_request_header = xhr.setRequestHeader;
+            xhr.setRequestHeader = function(name, value) {
+                headers[name] = value;
+                set_request_header.call(xhr, name, value);
+            };
+            return headers;
+        );
+
+        xhr.open("GET", "/").unwrap();
+        xhr.accept_json().unwrap();
+        assert_eq!(js!( return @{&headers}.Accept; ), "application/json");
+        xhr.abort();
+    }
+
+    #[test]
+    fn test_timeout() {
+        let xhr = XmlHttpRequest::new();
+        assert_eq!(xhr.timeout(), 0);
+
+        xhr.set_timeout(1500);
+        assert_eq!(xhr.timeout(), 1500);
+    }
+}
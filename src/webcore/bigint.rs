@@ -0,0 +1,121 @@
+use std::fmt;
+use webcore::value::{Value, ConversionError};
+use webcore::number;
+use webcore::try_from::TryFrom;
+
+/// A type representing a JavaScript `BigInt`.
+///
+/// The value is kept in its decimal representation, so it can
+/// hold integers of arbitrary size without losing any precision.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BigInt( pub(crate) String );
+
+impl BigInt {
+    /// Returns the decimal representation of this `BigInt`.
+    #[inline]
+    pub fn as_str( &self ) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for BigInt {
+    #[inline]
+    fn fmt( &self, formatter: &mut fmt::Formatter ) -> fmt::Result {
+        write!( formatter, "{}", self.0 )
+    }
+}
+
+impl From< BigInt > for Value {
+    #[inline]
+    fn from( value: BigInt ) -> Self {
+        Value::BigInt( value )
+    }
+}
+
+impl TryFrom< Value > for BigInt {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from( value: Value ) -> Result< Self, Self::Error > {
+        match value {
+            Value::BigInt( value ) => Ok( value ),
+            _ => Err( ConversionError::type_mismatch( &value, "BigInt".into() ) )
+        }
+    }
+}
+
+macro_rules! impl_bigint_conversions {
+    ($($kind:ty)+) => {
+        $(
+            impl From< $kind > for BigInt {
+                #[inline]
+                fn from( value: $kind ) -> Self {
+                    BigInt( value.to_string() )
+                }
+            }
+
+            impl TryFrom< BigInt > for $kind {
+                type Error = number::ConversionError;
+
+                #[inline]
+                fn try_from( value: BigInt ) -> Result< Self, Self::Error > {
+                    value.0.parse().map_err( |_| number::ConversionError::OutOfRange )
+                }
+            }
+
+            impl< 'a > TryFrom< &'a BigInt > for $kind {
+                type Error = number::ConversionError;
+
+                #[inline]
+                fn try_from( value: &'a BigInt ) -> Result< Self, Self::Error > {
+                    value.0.parse().map_err( |_| number::ConversionError::OutOfRange )
+                }
+            }
+        )+
+    };
+}
+
+impl_bigint_conversions!( i64 u64 i128 u128 );
+
+#[cfg(test)]
+mod tests {
+    use super::BigInt;
+    use webcore::value::Value;
+    use webcore::number;
+    use webcore::try_from::TryInto;
+
+    #[test]
+    fn integer_conversions() {
+        let value: BigInt = 12345678901234567890_u64.into();
+        assert_eq!( value.as_str(), "12345678901234567890" );
+
+        let result: Result< u64, _ > = value.clone().try_into();
+        assert_eq!( result, Ok( 12345678901234567890 ) );
+
+        let result: Result< i64, _ > = value.clone().try_into();
+        assert_eq!( result, Err( number::ConversionError::OutOfRange ) );
+
+        let result: Result< i128, _ > = value.try_into();
+        assert_eq!( result, Ok( 12345678901234567890 ) );
+
+        let value: BigInt = (-1_i64).into();
+        let result: Result< u64, _ > = value.try_into();
+        assert_eq!( result, Err( number::ConversionError::OutOfRange ) );
+    }
+
+    #[test]
+    fn round_trip_beyond_max_safe_integer() {
+        let value = js! { return BigInt( "9007199254740993" ); };
+        let bigint: BigInt = value.try_into().unwrap();
+        let result: Result< i64, _ > = (&bigint).try_into();
+        assert_eq!( result, Ok( 9007199254740993 ) );
+
+        let is_equal: bool = js! {
+            return @{&bigint} === BigInt( "9007199254740993" );
+        }.try_into().unwrap();
+        assert!( is_equal );
+
+        let value: Value = js! { return @{bigint}; };
+        assert_eq!( value, Value::BigInt( 9007199254740993_i64.into() ) );
+    }
+}
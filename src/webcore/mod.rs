@@ -11,6 +11,7 @@ pub mod try_from;
 pub mod object;
 pub mod array;
 pub mod symbol;
+pub mod bigint;
 pub mod type_name;
 pub mod unsafe_typed_array;
 pub mod mutfn;
@@ -325,6 +325,18 @@ impl< T, E > TypedPromise< T, E >
     {
         self.0.done( move |result| callback( result ) )
     }
+
+    /// A strongly typed version of [`Promise.to_future`](struct.Promise.html#method.to_future).
+    #[cfg(feature = "futures-support")]
+    #[inline]
+    pub fn to_future( &self ) -> PromiseFuture< T, E >
+        where T: 'static,
+              E: 'static,
+              T::Error: fmt::Debug,
+              E::Error: fmt::Debug
+    {
+        self.0.to_future()
+    }
 }
 
 impl< T, E > From< TypedPromise< T, E > > for Promise {
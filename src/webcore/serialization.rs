@@ -13,6 +13,7 @@ use webcore::try_from::{TryFrom, TryInto};
 use webcore::number::Number;
 use webcore::type_name::type_name;
 use webcore::symbol::Symbol;
+use webcore::bigint::BigInt;
 use webcore::unsafe_typed_array::UnsafeTypedArray;
 use webcore::mutfn::Mut;
 use webcore::once::Once;
@@ -46,7 +47,8 @@ pub enum Tag {
     FunctionMut = 12,
     FunctionOnce = 13,
     UnsafeTypedArray = 14,
-    Symbol = 15
+    Symbol = 15,
+    BigInt = 16
 }
 
 impl Default for Tag {
@@ -142,6 +144,13 @@ struct SerializedUntaggedSymbol {
     id: i32
 }
 
+#[repr(C)]
+#[derive(Clone, Debug)]
+struct SerializedUntaggedBigInt {
+    pointer: u32,
+    length: u32
+}
+
 #[repr(C)]
 #[derive(Debug)]
 struct SerializedUntaggedReference {
@@ -388,6 +397,18 @@ impl SerializedUntaggedSymbol {
     }
 }
 
+impl SerializedUntaggedBigInt {
+    #[inline]
+    fn deserialize( &self ) -> BigInt {
+        let digits = SerializedUntaggedString {
+            pointer: self.pointer,
+            length: self.length
+        }.deserialize();
+
+        BigInt( digits )
+    }
+}
+
 impl SerializedUntaggedReference {
     #[inline]
     fn deserialize( &self ) -> Reference {
@@ -444,6 +465,7 @@ untagged_boilerplate!( test_object, as_object, Tag::Object, SerializedUntaggedOb
 untagged_boilerplate!( test_string, as_string, Tag::Str, SerializedUntaggedString );
 untagged_boilerplate!( test_array, as_array, Tag::Array, SerializedUntaggedArray );
 untagged_boilerplate!( test_symbol, as_symbol, Tag::Symbol, SerializedUntaggedSymbol );
+untagged_boilerplate!( test_bigint, as_bigint, Tag::BigInt, SerializedUntaggedBigInt );
 untagged_boilerplate!( test_reference, as_reference, Tag::Reference, SerializedUntaggedReference );
 untagged_boilerplate!( test_function, as_function, Tag::Function, SerializedUntaggedFunction );
 untagged_boilerplate!( test_function_mut, as_function_mut, Tag::FunctionMut, SerializedUntaggedFunctionMut );
@@ -464,6 +486,7 @@ impl< 'a > SerializedValue< 'a > {
             Tag::True => Value::Bool( true ),
             Tag::Reference => self.as_reference().deserialize().into(),
             Tag::Symbol => self.as_symbol().deserialize().into(),
+            Tag::BigInt => self.as_bigint().deserialize().into(),
             Tag::Function |
             Tag::FunctionMut |
             Tag::FunctionOnce |
@@ -516,6 +539,19 @@ impl JsSerialize for Symbol {
 
 __js_serializable_boilerplate!( Symbol );
 
+impl JsSerialize for BigInt {
+    #[doc(hidden)]
+    #[inline]
+    fn _into_js< 'a >( &'a self ) -> SerializedValue< 'a > {
+        SerializedUntaggedBigInt {
+            pointer: self.0.as_ptr() as u32,
+            length: self.0.len() as u32
+        }.into()
+    }
+}
+
+__js_serializable_boilerplate!( BigInt );
+
 impl JsSerialize for Reference {
     #[doc(hidden)]
     #[inline]
@@ -780,7 +816,8 @@ impl JsSerialize for Value {
             Value::Number( ref value ) => value._into_js(),
             Value::Symbol( ref value ) => value._into_js(),
             Value::String( ref value ) => value._into_js(),
-            Value::Reference( ref value ) => value._into_js()
+            Value::Reference( ref value ) => value._into_js(),
+            Value::BigInt( ref value ) => value._into_js()
         }
     }
 }
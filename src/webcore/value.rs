@@ -13,8 +13,10 @@ use webcore::serialization::JsSerialize;
 use webcore::reference_type::ReferenceType;
 use webcore::instance_of::InstanceOf;
 use webcore::symbol::Symbol;
+use webcore::bigint::BigInt;
 use webcore::type_name::type_name_opt;
 use webapi::error::TypeError;
+use webapi::typed_array::{ArrayKind, TypedArray};
 
 /// A unit type representing JavaScript's `undefined`.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
@@ -165,7 +167,8 @@ pub enum Value {
     Number( Number ),
     Symbol( Symbol ),
     String( String ),
-    Reference( Reference )
+    Reference( Reference ),
+    BigInt( BigInt )
 }
 
 impl Value {
@@ -281,6 +284,19 @@ impl Value {
         }
     }
 
+    /// Copies the contents of a JavaScript typed array inside this `Value` into a `Vec`.
+    ///
+    /// This copies the elements directly from the typed array in one call, which is
+    /// much faster than converting it element by element with `try_into`.
+    ///
+    /// In cases where the value is not a typed array of the matching element type
+    /// (e.g. a `Float32Array` for `Vec< f32 >`) a `None` is returned.
+    #[inline]
+    pub fn into_typed_vec< T: ArrayKind >( self ) -> Option< Vec< T > > {
+        let array: TypedArray< T > = self.into_reference()?.try_into().ok()?;
+        Some( T::from_typed_array( &array ) )
+    }
+
     /// Converts a [Reference](struct.Reference.html) inside this `Value` into
     /// the given type `T`; doesn't check whenever the reference is really of type `T`.
     ///
@@ -308,6 +324,27 @@ impl Value {
             _ => None
         }
     }
+
+    /// Compares this `Value` with another one using JavaScript's
+    /// strict equality operator (`===`).
+    ///
+    /// Unlike `PartialEq` this always follows JavaScript semantics,
+    /// e.g. `NaN` is never equal to itself.
+    pub fn js_equals( &self, other: &Value ) -> bool {
+        js!(
+            return @{self} === @{other};
+        ).try_into().unwrap()
+    }
+
+    /// Compares this `Value` with another one using the `Object.is` algorithm.
+    ///
+    /// This is the same as [js_equals](#method.js_equals) except that `NaN` is equal
+    /// to itself and `+0` is not equal to `-0`.
+    pub fn same_value( &self, other: &Value ) -> bool {
+        js!(
+            return Object.is( @{self}, @{other} );
+        ).try_into().unwrap()
+    }
 }
 
 impl AsRef< Value > for Value {
@@ -845,7 +882,8 @@ fn extract_type_name( value: &Value ) -> Cow< 'static, str > {
         Value::Number( _ ) => "Number".into(),
         Value::Symbol( _ ) => "Symbol".into(),
         Value::String( _ ) => "String".into(),
-        Value::Reference( _ ) => "Reference".into()
+        Value::Reference( _ ) => "Reference".into(),
+        Value::BigInt( _ ) => "BigInt".into()
     }
 }
 
@@ -1254,6 +1292,36 @@ mod tests {
         assert!( &reference == &value );
     }
 
+    #[test]
+    fn js_equals() {
+        let object = js! { return {}; };
+        let same_object = js! { return @{&object}; };
+        let other_object = js! { return {}; };
+
+        assert!( object.js_equals( &same_object ) );
+        assert!( !object.js_equals( &other_object ) );
+        assert!( Value::from( 1 ).js_equals( &Value::from( 1.0 ) ) );
+        assert!( !Value::from( 1 ).js_equals( &Value::from( "1" ) ) );
+        assert!( !Value::Null.js_equals( &Value::Undefined ) );
+
+        let nan = js! { return NaN; };
+        assert!( !nan.js_equals( &nan ) );
+    }
+
+    #[test]
+    fn same_value() {
+        let object = js! { return {}; };
+        let same_object = js! { return @{&object}; };
+        let other_object = js! { return {}; };
+
+        assert!( object.same_value( &same_object ) );
+        assert!( !object.same_value( &other_object ) );
+
+        let nan = js! { return NaN; };
+        assert!( nan.same_value( &nan ) );
+        assert!( !Value::from( 1 ).same_value( &Value::from( "1" ) ) );
+    }
+
     #[derive(Clone, Debug, PartialEq, Eq, ReferenceType)]
     #[reference(instance_of = "Error")]
     pub struct Error( Reference );
@@ -176,6 +176,10 @@ Module.STDWEB_PRIVATE.to_js = function to_js( address ) {
         }
     } else if( kind === 15 ) {
         return Module.STDWEB_PRIVATE.get_raw_value( Module.HEAPU32[ address / 4 ] );
+    } else if( kind === 16 ) {
+        var pointer = Module.HEAPU32[ address / 4 ];
+        var length = Module.HEAPU32[ (address + 4) / 4 ];
+        return BigInt( Module.STDWEB_PRIVATE.to_js_string( pointer, length ) );
     }
 };
 
@@ -272,6 +276,9 @@ Module.STDWEB_PRIVATE.from_js = function from_js( address, value ) {
         var id = Module.STDWEB_PRIVATE.register_raw_value( value );
         Module.HEAPU8[ address + 12 ] = 15;
         Module.HEAP32[ address / 4 ] = id;
+    } else if( kind === "[object BigInt]" ) {
+        Module.HEAPU8[ address + 12 ] = 16;
+        Module.STDWEB_PRIVATE.to_utf8_string( address, value.toString() );
     } else {
         var refid = Module.STDWEB_PRIVATE.acquire_rust_reference( value );
         Module.HEAPU8[ address + 12 ] = 9;